@@ -19,6 +19,22 @@ struct Integration {
     project_id: Option<String>,
     #[serde(rename = "teamId")]
     team_id: Option<String>,
+    #[serde(rename = "apiSecret", skip_serializing_if = "Option::is_none")]
+    api_secret: Option<String>, // Secondary credential (e.g. Amplitude secret key)
+    #[serde(rename = "baseUrl", skip_serializing_if = "Option::is_none")]
+    base_url: Option<String>, // Region or self-hosted API base URL override
+    #[serde(rename = "eventName", skip_serializing_if = "Option::is_none")]
+    event_name: Option<String>, // Key event to segment on (Amplitude)
+    #[serde(rename = "serviceId", skip_serializing_if = "Option::is_none")]
+    service_id: Option<String>, // Service within the project (Railway)
+    #[serde(rename = "metricIds", skip_serializing_if = "Option::is_none")]
+    metric_ids: Option<String>, // Saved metric IDs by role, e.g. "errors=123, sessions=456" (LogRocket)
+    #[serde(rename = "historyDays", skip_serializing_if = "Option::is_none")]
+    history_days: Option<u32>, // How far back to page through history (deployments)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collections: Option<Vec<String>>, // Collections to count (Firestore)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    queries: Option<Vec<SqlMetricQuery>>, // Named read-only queries (Postgres)
     enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     platform: Option<String>, // Which platform this integration belongs to
@@ -31,6 +47,26 @@ struct App {
     #[serde(default)]
     platforms: Vec<String>, // Multi-platform support: web, mobile, service, fun
     integrations: Vec<Integration>,
+    #[serde(rename = "googleCalendar", default)]
+    google_calendar: Option<GoogleOAuthTokens>, // Project's Google connection (Calendar, Gmail, Analytics)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct GoogleOAuthTokens {
+    #[serde(rename = "accessToken")]
+    access_token: Option<String>,
+    #[serde(rename = "refreshToken")]
+    refresh_token: Option<String>,
+}
+
+// ==========================================
+// Shared Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct DailyCount {
+    date: String,
+    count: i64,
 }
 
 // ==========================================
@@ -131,25 +167,225 @@ struct StripeEvent {
 }
 
 // ==========================================
-// Vercel Types
+// Deployment Types (shared by every hosting provider)
 // ==========================================
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum DeploymentState {
+    Queued,
+    Building,
+    Ready,
+    Error,
+    Canceled,
+    Inactive, // Being torn down or idled, e.g. Railway REMOVING/SLEEPING
+    Unknown,
+}
+
+impl DeploymentState {
+    fn is_finished(self) -> bool {
+        matches!(self, DeploymentState::Ready | DeploymentState::Error | DeploymentState::Canceled)
+    }
+
+    // Deploys that actually went out and either succeeded or broke. Canceled and
+    // inactive deploys never count toward success rate, build time or health.
+    fn is_attempt(self) -> bool {
+        matches!(self, DeploymentState::Ready | DeploymentState::Error)
+    }
+
+    // Lowercase status used for the provider badge ("ready", "building", ...)
+    fn status(self) -> &'static str {
+        match self {
+            DeploymentState::Queued => "queued",
+            DeploymentState::Building => "building",
+            DeploymentState::Ready => "ready",
+            DeploymentState::Error => "error",
+            DeploymentState::Canceled => "canceled",
+            DeploymentState::Inactive => "inactive",
+            DeploymentState::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 struct Deployment {
     id: String,
+    provider: String,
     name: String,
-    state: String,
+    environment: String, // "production" or "preview"
+    state: DeploymentState,
     #[serde(rename = "createdAt")]
-    created_at: String,
+    created_at: String, // RFC 3339, UTC
+    #[serde(rename = "startedAt")]
+    started_at: Option<String>,
+    #[serde(rename = "finishedAt")]
+    finished_at: Option<String>,
+    duration: Option<i64>, // Seconds from start to finish
     url: String,
+    #[serde(rename = "commitSha")]
+    commit_sha: Option<String>,
+    #[serde(rename = "commitMessage")]
+    commit_message: Option<String>,
+    #[serde(rename = "commitAuthor")]
+    commit_author: Option<String>,
 }
 
+// Deployment list plus summary stats; flattened into every hosting provider's
+// metrics and used for the merged deploy timeline.
 #[derive(Debug, Serialize, Deserialize)]
-struct VercelMetrics {
+struct DeploymentHistory {
     deployments: Vec<Deployment>,
     #[serde(rename = "lastDeployedAt")]
     last_deployed_at: Option<String>,
     status: String,
+    #[serde(rename = "windowDays")]
+    window_days: u32,
+    #[serde(rename = "totalDeployments")]
+    total_deployments: i32,
+    #[serde(rename = "failedDeployments")]
+    failed_deployments: i32,
+    #[serde(rename = "canceledDeployments")]
+    canceled_deployments: i32,
+    #[serde(rename = "productionDeployments")]
+    production_deployments: i32,
+    #[serde(rename = "previewDeployments")]
+    preview_deployments: i32,
+    #[serde(rename = "successRate")]
+    success_rate: f64,
+    #[serde(rename = "averageBuildTime")]
+    average_build_time: f64, // Seconds
+}
+
+// Mirrors the frontend ActivityEvent for deployment_success / deployment_failed
+#[derive(Debug, Serialize, Deserialize)]
+struct DeploymentActivity {
+    id: String,
+    source: String,
+    #[serde(rename = "type")]
+    activity_type: String,
+    timestamp: i64,
+    title: String,
+    description: Option<String>,
+    #[serde(rename = "deploymentUrl")]
+    deployment_url: String,
+    #[serde(rename = "isPositive")]
+    is_positive: bool,
+}
+
+// ==========================================
+// Vercel Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VercelMetrics {
+    #[serde(flatten)]
+    history: DeploymentHistory,
+    domains: Vec<VercelDomain>,
+    usage: Option<VercelUsage>,
+    #[serde(rename = "recentFailures")]
+    recent_failures: Vec<FailedDeployment>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VercelDomain {
+    name: String,
+    verified: bool,
+    misconfigured: Option<bool>, // None when the DNS config wasn't checked
+    redirect: Option<String>,
+    #[serde(rename = "gitBranch")]
+    git_branch: Option<String>,
+    ssl: Option<SslCertificate>, // None when the certificate wasn't checked or the host was unreachable
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SslCertificate {
+    valid: bool, // Trusted chain that matches the domain and hasn't expired
+    #[serde(rename = "expiresAt")]
+    expires_at: Option<String>,
+    #[serde(rename = "daysRemaining")]
+    days_remaining: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VercelUsage {
+    #[serde(rename = "periodStart")]
+    period_start: String,
+    #[serde(rename = "periodEnd")]
+    period_end: String,
+    #[serde(rename = "bandwidthBytes")]
+    bandwidth_bytes: Option<i64>,
+    #[serde(rename = "functionInvocations")]
+    function_invocations: Option<i64>,
+    #[serde(rename = "buildMinutes")]
+    build_minutes: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FailedDeployment {
+    id: String,
+    url: String,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+    #[serde(rename = "commitMessage")]
+    commit_message: Option<String>,
+    #[serde(rename = "errorCode")]
+    error_code: Option<String>,
+    #[serde(rename = "errorMessage")]
+    error_message: Option<String>,
+    #[serde(rename = "logLines")]
+    log_lines: Vec<String>,
+}
+
+// ==========================================
+// Netlify Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NetlifyForm {
+    id: String,
+    name: String,
+    #[serde(rename = "submissionCount")]
+    submission_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NetlifyBandwidth {
+    #[serde(rename = "usedBytes")]
+    used_bytes: i64,
+    #[serde(rename = "includedBytes")]
+    included_bytes: i64,
+    #[serde(rename = "periodStart")]
+    period_start: Option<String>,
+    #[serde(rename = "periodEnd")]
+    period_end: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NetlifyMetrics {
+    #[serde(flatten)]
+    history: DeploymentHistory,
+    #[serde(rename = "currentDeploy")]
+    current_deploy: Option<Deployment>, // The deploy currently published to production
+    forms: Vec<NetlifyForm>,
+    #[serde(rename = "formSubmissions")]
+    form_submissions: i64,
+    bandwidth: Option<NetlifyBandwidth>,
+}
+
+// ==========================================
+// Service Hosting Types (Railway / Render / Fly.io)
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HostingMetrics {
+    provider: String,
+    #[serde(flatten)]
+    history: DeploymentHistory,
+    #[serde(rename = "runningInstances")]
+    running_instances: i32,
+    #[serde(rename = "totalInstances")]
+    total_instances: i32,
+    health: String, // "healthy", "degraded", "down" or "unknown"
 }
 
 // ==========================================
@@ -168,20 +404,422 @@ struct PostHogMetrics {
     unique_users_7d: i64,
 }
 
+// ==========================================
+// Amplitude Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AmplitudeEventSegment {
+    #[serde(rename = "eventName")]
+    event_name: String,
+    #[serde(rename = "total30d")]
+    total_30d: i64,
+    #[serde(rename = "uniqueUsers30d")]
+    unique_users_30d: i64,
+    daily: Vec<DailyCount>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AmplitudeMetrics {
+    dau: i64,
+    wau: i64,
+    mau: i64,
+    #[serde(rename = "newUsers1d")]
+    new_users_1d: i64,
+    #[serde(rename = "newUsers7d")]
+    new_users_7d: i64,
+    #[serde(rename = "newUsers30d")]
+    new_users_30d: i64,
+    #[serde(rename = "dailyActiveUsers")]
+    daily_active_users: Vec<DailyCount>,
+    #[serde(rename = "dailyNewUsers")]
+    daily_new_users: Vec<DailyCount>,
+    event: Option<AmplitudeEventSegment>,
+}
+
+// ==========================================
+// Web Analytics Types (PostHog / Plausible / GA4 / Umami / Fathom)
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RankedItem {
+    name: String,
+    count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WebAnalyticsDay {
+    date: String,
+    visitors: i64,
+    pageviews: i64,
+}
+
+// Normalised shape every web analytics provider produces.
+// All counts cover the last WEB_ANALYTICS_DAYS days.
+#[derive(Debug, Serialize, Deserialize)]
+struct WebAnalyticsMetrics {
+    provider: String,
+    visitors: i64,
+    pageviews: i64,
+    sessions: i64,
+    #[serde(rename = "bounceRate")]
+    bounce_rate: f64, // Percentage (0-100)
+    #[serde(rename = "topPages")]
+    top_pages: Vec<RankedItem>,
+    #[serde(rename = "topSources")]
+    top_sources: Vec<RankedItem>,
+    daily: Vec<WebAnalyticsDay>,
+}
+
 // ==========================================
 // Supabase Types
 // ==========================================
 
+#[derive(Debug, Serialize, Deserialize)]
+struct AuthProviderCount {
+    provider: String, // Matches the frontend AuthProvider ("email", "google", ..., "unknown")
+    count: i32,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct SupabaseMetrics {
     #[serde(rename = "totalUsers")]
     total_users: i32,
     #[serde(rename = "newUsers7d")]
-    new_users_7d: i32,
+    new_users_7d: i32, // Full user breakdown lives in AppMetrics.users
     #[serde(rename = "databaseSize")]
     database_size: String,
     #[serde(rename = "apiRequests24h")]
     api_requests_24h: i64,
+    // Only filled in when a personal access token is configured (Management API)
+    #[serde(rename = "databaseSizeBytes", default)]
+    database_size_bytes: Option<i64>,
+    #[serde(rename = "storageSizeBytes", default)]
+    storage_size_bytes: Option<i64>,
+    #[serde(rename = "egressBytes24h", default)]
+    egress_bytes_24h: Option<i64>,
+    #[serde(rename = "apiRequestsByService", default)]
+    api_requests_by_service: Option<SupabaseApiRequests>,
+}
+
+// Requests over the last 24h, split by Supabase service
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct SupabaseApiRequests {
+    rest: i64,
+    auth: i64,
+    storage: i64,
+    realtime: i64,
+}
+
+#[derive(Debug, Default)]
+struct SupabaseUsage {
+    database_size_bytes: Option<i64>,
+    storage_size_bytes: Option<i64>,
+    egress_bytes_24h: Option<i64>,
+    api_requests: Option<SupabaseApiRequests>,
+}
+
+// ==========================================
+// Firebase Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FirestoreCollectionCount {
+    collection: String,
+    count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FirebaseMetrics {
+    #[serde(rename = "totalUsers")]
+    total_users: i32,
+    #[serde(rename = "newUsers7d")]
+    new_users_7d: i32, // Full user breakdown lives in AppMetrics.users
+    collections: Vec<FirestoreCollectionCount>,
+}
+
+// ==========================================
+// User Growth Types
+// ==========================================
+
+// Shared by every auth provider so the same user widgets work for all of them
+#[derive(Debug, Serialize, Deserialize)]
+struct UserGrowthMetrics {
+    provider: String,
+    #[serde(rename = "totalUsers")]
+    total_users: i64,
+    #[serde(rename = "newUsers1d")]
+    new_users_1d: i64,
+    #[serde(rename = "newUsers7d")]
+    new_users_7d: i64,
+    #[serde(rename = "newUsers30d")]
+    new_users_30d: i64,
+    #[serde(rename = "dailySignups")]
+    daily_signups: Vec<DailyCount>, // Last 30 days, oldest first
+    #[serde(rename = "authProviders")]
+    auth_providers: Vec<AuthProviderCount>, // Sorted by count, descending
+    #[serde(rename = "verifiedUsers")]
+    verified_users: Option<i64>, // Confirmed email or phone
+    // None when the provider doesn't track sign-in activity
+    #[serde(rename = "activeUsers1d")]
+    active_users_1d: Option<i64>,
+    #[serde(rename = "activeUsers7d")]
+    active_users_7d: Option<i64>,
+    #[serde(rename = "activeUsers30d")]
+    active_users_30d: Option<i64>,
+}
+
+// ==========================================
+// Database Platform Types
+// ==========================================
+
+// A PlanetScale/Neon branch or an Atlas cluster
+#[derive(Debug, Serialize, Deserialize)]
+struct DatabaseInstance {
+    name: String,
+    status: String, // "ready", "sleeping", "provisioning", "paused", "error" or "unknown"
+    region: Option<String>,
+    primary: bool, // Production branch / default branch
+    #[serde(rename = "storageBytes")]
+    storage_bytes: Option<i64>,
+}
+
+// Shared by PlanetScale, Neon and MongoDB Atlas; fields a provider can't report stay None
+#[derive(Debug, Serialize, Deserialize)]
+struct DatabaseHealthMetrics {
+    provider: String,
+    health: String, // "healthy", "degraded", "down" or "unknown"
+    instances: Vec<DatabaseInstance>,
+    #[serde(rename = "storageUsedBytes")]
+    storage_used_bytes: Option<i64>,
+    #[serde(rename = "storageLimitBytes")]
+    storage_limit_bytes: Option<i64>,
+    #[serde(rename = "computeHours")]
+    compute_hours: Option<f64>, // Current billing period
+    connections: Option<i64>,
+}
+
+// ==========================================
+// Error Tracking Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ErrorIssue {
+    id: String,
+    title: String,
+    culprit: Option<String>,
+    level: String,
+    count: i64, // Events in the stats window
+    #[serde(rename = "userCount")]
+    user_count: i64,
+    #[serde(rename = "firstSeen")]
+    first_seen: Option<String>,
+    #[serde(rename = "lastSeen")]
+    last_seen: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReleaseHealth {
+    version: String,
+    sessions: i64,
+    #[serde(rename = "crashFreeRate")]
+    crash_free_rate: Option<f64>, // Percentage (0-100)
+}
+
+// Shared by every error source; fields a provider can't report stay None
+#[derive(Debug, Serialize, Deserialize)]
+struct ErrorTrackingMetrics {
+    provider: String,
+    #[serde(rename = "unresolvedIssues")]
+    unresolved_issues: Option<i64>,
+    #[serde(rename = "newIssues24h")]
+    new_issues_24h: Option<i64>,
+    #[serde(rename = "events24h")]
+    events_24h: i64,
+    #[serde(rename = "dailyEvents")]
+    daily_events: Vec<DailyCount>, // Oldest first
+    #[serde(rename = "crashFreeRate")]
+    crash_free_rate: Option<f64>, // Percentage of sessions (0-100)
+    releases: Vec<ReleaseHealth>,
+    #[serde(rename = "topIssues")]
+    top_issues: Vec<ErrorIssue>,
+    // Session replay tools (LogRocket) also report sessions and UX friction
+    #[serde(rename = "sessions24h")]
+    sessions_24h: Option<i64>,
+    friction: Option<FrictionSignals>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FrictionSignals {
+    #[serde(rename = "rageClicks24h")]
+    rage_clicks_24h: Option<i64>,
+    #[serde(rename = "deadClicks24h")]
+    dead_clicks_24h: Option<i64>,
+    #[serde(rename = "errorSessions24h")]
+    error_sessions_24h: Option<i64>, // Sessions with at least one error
+}
+
+// ==========================================
+// Email Delivery Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct EmailDeliveryCounts {
+    sent: i64,
+    delivered: i64,
+    bounced: i64,
+    complained: i64, // Marked as spam
+    opened: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EmailDomain {
+    name: String,
+    status: String, // Provider's own status, e.g. "verified", "pending", "failed"
+    verified: bool,
+}
+
+// Shared by Resend and SendGrid
+#[derive(Debug, Serialize, Deserialize)]
+struct EmailDeliveryMetrics {
+    provider: String,
+    today: EmailDeliveryCounts, // Since 00:00 UTC; SendGrid only aggregates stats by day
+    #[serde(rename = "last7d")]
+    last_7d: EmailDeliveryCounts,
+    #[serde(rename = "deliveryRate")]
+    delivery_rate: Option<f64>, // Percentage of sent over 7d (0-100)
+    #[serde(rename = "bounceRate")]
+    bounce_rate: Option<f64>,
+    domains: Vec<EmailDomain>,
+}
+
+// ==========================================
+// Newsletter Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NewsletterCampaign {
+    id: String,
+    subject: String,
+    #[serde(rename = "sentAt")]
+    sent_at: Option<String>,
+    recipients: i64,
+    #[serde(rename = "openRate")]
+    open_rate: Option<f64>, // Percentage (0-100)
+    #[serde(rename = "clickRate")]
+    click_rate: Option<f64>,
+}
+
+// Shared by ConvertKit and Mailchimp
+#[derive(Debug, Serialize, Deserialize)]
+struct NewsletterMetrics {
+    provider: String,
+    #[serde(rename = "totalSubscribers")]
+    total_subscribers: i64,
+    #[serde(rename = "newSubscribers30d")]
+    new_subscribers_30d: i64,
+    #[serde(rename = "unsubscribed30d")]
+    unsubscribed_30d: i64,
+    #[serde(rename = "subscriberGrowth")]
+    subscriber_growth: Vec<DailyCount>, // List size at the end of each day, oldest first
+    #[serde(rename = "latestCampaign")]
+    latest_campaign: Option<NewsletterCampaign>,
+}
+
+// ==========================================
+// Support Types
+// ==========================================
+
+// Shared by Intercom and Crisp; fields a provider can't report stay None
+#[derive(Debug, Serialize, Deserialize)]
+struct SupportMetrics {
+    provider: String,
+    #[serde(rename = "openConversations")]
+    open_conversations: i64,
+    #[serde(rename = "unassignedConversations")]
+    unassigned_conversations: Option<i64>, // Open and not assigned to anyone
+    #[serde(rename = "newConversations7d")]
+    new_conversations_7d: i64,
+    #[serde(rename = "dailyNewConversations")]
+    daily_new_conversations: Vec<DailyCount>, // Oldest first
+    #[serde(rename = "medianFirstResponseSeconds")]
+    median_first_response_seconds: Option<f64>, // Over conversations created in the window
+}
+
+// ==========================================
+// Community Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CommunityMetrics {
+    provider: String,
+    name: String,
+    #[serde(rename = "totalMembers")]
+    total_members: i64,
+    #[serde(rename = "onlineMembers")]
+    online_members: i64,
+    // From current members' join dates, so members who left aren't counted.
+    // None when the bot lacks the Server Members intent or the server is too large to list.
+    #[serde(rename = "newMembers7d")]
+    new_members_7d: Option<i64>,
+    #[serde(rename = "newMembers30d")]
+    new_members_30d: Option<i64>,
+    #[serde(rename = "dailyJoins")]
+    daily_joins: Option<Vec<DailyCount>>, // Oldest first
+}
+
+// ==========================================
+// Booking Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BookingMetrics {
+    provider: String,
+    #[serde(rename = "upcomingBookings")]
+    upcoming_bookings: i64,
+    // Bookings that started in the last 30 days
+    #[serde(rename = "bookings30d")]
+    bookings_30d: i64,
+    #[serde(rename = "cancellations30d")]
+    cancellations_30d: i64,
+    #[serde(rename = "noShows30d")]
+    no_shows_30d: i64,
+}
+
+// ==========================================
+// Custom SQL Types
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+enum SqlResultKind {
+    #[default]
+    Scalar,     // One row; the `value` column
+    Timeseries, // One row per point; `date` and `value` columns
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct SqlMetricQuery {
+    name: String,
+    sql: String,
+    #[serde(default)]
+    kind: SqlResultKind,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CustomMetricPoint {
+    date: String,
+    value: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CustomMetric {
+    name: String,
+    kind: SqlResultKind,
+    value: Option<f64>, // Scalar result, or the latest point of a time series
+    series: Vec<CustomMetricPoint>, // Oldest first; empty for scalars
+    error: Option<String>,
 }
 
 // ==========================================
@@ -192,10 +830,37 @@ struct SupabaseMetrics {
 struct AppMetrics {
     stripe: Option<StripeMetrics>,
     vercel: Option<VercelMetrics>,
+    netlify: Option<NetlifyMetrics>,
+    hosting: Option<Vec<HostingMetrics>>, // One entry per host (Railway, Render, Fly.io)
     posthog: Option<PostHogMetrics>,
     supabase: Option<SupabaseMetrics>,
+    firebase: Option<FirebaseMetrics>,
+    users: Option<Vec<UserGrowthMetrics>>, // One entry per auth provider
+    database: Option<Vec<DatabaseHealthMetrics>>, // One entry per database host
+    sentry: Option<ErrorTrackingMetrics>,
+    logrocket: Option<ErrorTrackingMetrics>,
+    email: Option<Vec<EmailDeliveryMetrics>>, // One entry per email provider
+    newsletter: Option<Vec<NewsletterMetrics>>, // One entry per newsletter provider
+    support: Option<Vec<SupportMetrics>>, // One entry per support provider
+    community: Option<CommunityMetrics>,
+    bookings: Option<BookingMetrics>,
+    // Same shape as fetch_google_calendar's events
+    #[serde(rename = "calendarEvents")]
+    calendar_events: Option<Vec<serde_json::Value>>,
+    amplitude: Option<AmplitudeMetrics>,
+    #[serde(rename = "webAnalytics")]
+    web_analytics: Option<Vec<WebAnalyticsMetrics>>,
+    // Set when GA4 had to refresh the project's Google token, so the frontend can store it
+    #[serde(rename = "googleAccessToken", default, skip_serializing_if = "Option::is_none")]
+    google_access_token: Option<String>,
+    #[serde(rename = "customMetrics")]
+    custom_metrics: Option<Vec<CustomMetric>>,
     #[serde(rename = "stripeEvents")]
     stripe_events: Option<Vec<StripeEvent>>,
+    #[serde(rename = "deployTimeline")]
+    deploy_timeline: Option<DeploymentHistory>,
+    #[serde(rename = "deployActivity")]
+    deploy_activity: Option<Vec<DeploymentActivity>>,
     #[serde(rename = "lastUpdated")]
     last_updated: String,
 }
@@ -213,6 +878,18 @@ struct MetricSnapshot {
     vercel: Option<VercelSnapshot>,
     posthog: Option<PostHogSnapshot>,
     supabase: Option<SupabaseSnapshot>,
+    amplitude: Option<AmplitudeSnapshot>,
+    // One entry per provider; older snapshots stored a single object
+    #[serde(rename = "webAnalytics", default, deserialize_with = "one_or_many")]
+    web_analytics: Option<Vec<WebAnalyticsSnapshot>>,
+    deployments: Option<DeploymentSnapshot>,
+    #[serde(rename = "customMetrics")]
+    custom_metrics: Option<Vec<CustomMetricSnapshot>>,
+    users: Option<UserSnapshot>,
+    database: Option<Vec<DatabaseSnapshot>>,
+    sentry: Option<ErrorTrackingSnapshot>,
+    newsletter: Option<NewsletterSnapshot>,
+    community: Option<CommunitySnapshot>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -230,6 +907,10 @@ struct VercelSnapshot {
     deployments: i32,
     #[serde(rename = "successRate")]
     success_rate: f64,
+    #[serde(rename = "failedDeployments", default)]
+    failed_deployments: i32,
+    #[serde(rename = "averageBuildTime", default)]
+    average_build_time: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -246,6 +927,122 @@ struct SupabaseSnapshot {
     total_users: i32,
     #[serde(rename = "apiRequests")]
     api_requests: i64,
+    #[serde(rename = "databaseSizeBytes", default)]
+    database_size_bytes: Option<i64>,
+    #[serde(rename = "storageSizeBytes", default)]
+    storage_size_bytes: Option<i64>,
+    #[serde(rename = "egressBytes", default)]
+    egress_bytes: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct AmplitudeSnapshot {
+    dau: i64,
+    wau: i64,
+    mau: i64,
+    #[serde(rename = "newUsers")]
+    new_users: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct WebAnalyticsSnapshot {
+    provider: String,
+    visitors: i64,
+    pageviews: i64,
+    sessions: i64,
+    #[serde(rename = "bounceRate")]
+    bounce_rate: f64,
+}
+
+// Totals across every hosting provider's deploys
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct DeploymentSnapshot {
+    deployments: i32,
+    #[serde(rename = "successRate")]
+    success_rate: f64,
+    #[serde(rename = "failedDeployments")]
+    failed_deployments: i32,
+    #[serde(rename = "canceledDeployments")]
+    canceled_deployments: i32,
+    #[serde(rename = "productionDeployments")]
+    production_deployments: i32,
+    #[serde(rename = "averageBuildTime")]
+    average_build_time: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct ErrorTrackingSnapshot {
+    #[serde(rename = "unresolvedIssues")]
+    unresolved_issues: Option<i64>,
+    #[serde(rename = "newIssues")]
+    new_issues: Option<i64>,
+    events: i64,
+    #[serde(rename = "crashFreeRate")]
+    crash_free_rate: Option<f64>,
+}
+
+// Auth provider agnostic, so history survives switching e.g. Supabase auth to Clerk
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct UserSnapshot {
+    provider: String,
+    #[serde(rename = "totalUsers")]
+    total_users: i64,
+    #[serde(rename = "newUsers")]
+    new_users: i64, // Last 24h
+    #[serde(rename = "activeUsers")]
+    active_users: Option<i64>, // Last 30 days
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct DatabaseSnapshot {
+    provider: String,
+    health: String,
+    #[serde(rename = "storageUsedBytes")]
+    storage_used_bytes: Option<i64>,
+    #[serde(rename = "computeHours")]
+    compute_hours: Option<f64>,
+    connections: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct NewsletterSnapshot {
+    provider: String,
+    subscribers: i64,
+    #[serde(rename = "newSubscribers")]
+    new_subscribers: i64, // Last 30 days
+    unsubscribed: i64,    // Last 30 days
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CommunitySnapshot {
+    provider: String,
+    members: i64,
+    online: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CustomMetricSnapshot {
+    name: String,
+    value: f64,
+}
+
+// Reads a snapshot field that used to hold one entry and now holds a list
+fn one_or_many<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    Ok(Option::<OneOrMany<T>>::deserialize(deserializer)?.map(|value| match value {
+        OneOrMany::One(item) => vec![item],
+        OneOrMany::Many(items) => items,
+    }))
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -365,8 +1162,10 @@ fn save_snapshot(app_id: String, metrics: String) -> Result<(), String> {
             arr: s.arr,
         }),
         vercel: app_metrics.vercel.as_ref().map(|v| VercelSnapshot {
-            deployments: v.deployments.len() as i32,
-            success_rate: 100.0, // Simplified
+            deployments: v.history.total_deployments,
+            success_rate: v.history.success_rate,
+            failed_deployments: v.history.failed_deployments,
+            average_build_time: v.history.average_build_time,
         }),
         posthog: app_metrics.posthog.as_ref().map(|p| PostHogSnapshot {
             unique_users: p.unique_users_7d,
@@ -375,6 +1174,83 @@ fn save_snapshot(app_id: String, metrics: String) -> Result<(), String> {
         supabase: app_metrics.supabase.as_ref().map(|s| SupabaseSnapshot {
             total_users: s.total_users,
             api_requests: s.api_requests_24h,
+            database_size_bytes: s.database_size_bytes,
+            storage_size_bytes: s.storage_size_bytes,
+            egress_bytes: s.egress_bytes_24h,
+        }),
+        amplitude: app_metrics.amplitude.as_ref().map(|a| AmplitudeSnapshot {
+            dau: a.dau,
+            wau: a.wau,
+            mau: a.mau,
+            new_users: a.new_users_1d,
+        }),
+        web_analytics: app_metrics.web_analytics.as_ref().map(|sources| {
+            sources
+                .iter()
+                .map(|w| WebAnalyticsSnapshot {
+                    provider: w.provider.clone(),
+                    visitors: w.visitors,
+                    pageviews: w.pageviews,
+                    sessions: w.sessions,
+                    bounce_rate: w.bounce_rate,
+                })
+                .collect()
+        }),
+        deployments: app_metrics.deploy_timeline.as_ref().map(|d| DeploymentSnapshot {
+            deployments: d.total_deployments,
+            success_rate: d.success_rate,
+            failed_deployments: d.failed_deployments,
+            canceled_deployments: d.canceled_deployments,
+            production_deployments: d.production_deployments,
+            average_build_time: d.average_build_time,
+        }),
+        custom_metrics: app_metrics.custom_metrics.as_ref().map(|custom| {
+            custom
+                .iter()
+                .filter_map(|m| {
+                    Some(CustomMetricSnapshot {
+                        name: m.name.clone(),
+                        value: m.value?,
+                    })
+                })
+                .collect()
+        }),
+        // The largest auth provider stands in for the app's user base
+        users: app_metrics.users.iter().flatten().max_by_key(|u| u.total_users).map(|u| UserSnapshot {
+            provider: u.provider.clone(),
+            total_users: u.total_users,
+            new_users: u.new_users_1d,
+            active_users: u.active_users_30d,
+        }),
+        database: app_metrics.database.as_ref().map(|databases| {
+            databases
+                .iter()
+                .map(|d| DatabaseSnapshot {
+                    provider: d.provider.clone(),
+                    health: d.health.clone(),
+                    storage_used_bytes: d.storage_used_bytes,
+                    compute_hours: d.compute_hours,
+                    connections: d.connections,
+                })
+                .collect()
+        }),
+        sentry: app_metrics.sentry.as_ref().map(|e| ErrorTrackingSnapshot {
+            unresolved_issues: e.unresolved_issues,
+            new_issues: e.new_issues_24h,
+            events: e.events_24h,
+            crash_free_rate: e.crash_free_rate,
+        }),
+        // The largest list stands in for the app's audience
+        newsletter: app_metrics.newsletter.iter().flatten().max_by_key(|n| n.total_subscribers).map(|n| NewsletterSnapshot {
+            provider: n.provider.clone(),
+            subscribers: n.total_subscribers,
+            new_subscribers: n.new_subscribers_30d,
+            unsubscribed: n.unsubscribed_30d,
+        }),
+        community: app_metrics.community.as_ref().map(|c| CommunitySnapshot {
+            provider: c.provider.clone(),
+            members: c.total_members,
+            online: c.online_members,
         }),
     };
 
@@ -412,9 +1288,28 @@ async fn fetch_app_metrics(app: String) -> Result<String, String> {
     let mut metrics = AppMetrics {
         stripe: None,
         vercel: None,
+        netlify: None,
+        hosting: None,
         posthog: None,
         supabase: None,
+        firebase: None,
+        users: None,
+        database: None,
+        sentry: None,
+        logrocket: None,
+        email: None,
+        newsletter: None,
+        support: None,
+        community: None,
+        bookings: None,
+        calendar_events: None,
+        amplitude: None,
+        web_analytics: None,
+        google_access_token: None,
+        custom_metrics: None,
         stripe_events: None,
+        deploy_timeline: None,
+        deploy_activity: None,
         last_updated: chrono::Utc::now().to_rfc3339(),
     };
 
@@ -431,17 +1326,56 @@ async fn fetch_app_metrics(app: String) -> Result<String, String> {
                 }
             }
             "vercel" => {
-                if let (Some(api_key), Some(project_id)) =
-                    (&integration.api_key, &integration.project_id)
-                {
+                if let Some(vercel) = VercelDeployments::from_integration(integration) {
                     metrics.vercel = fetch_vercel_metrics(
                         &client,
-                        api_key,
-                        project_id,
-                        integration.team_id.as_deref(),
+                        &vercel,
+                        deployment_window_days(integration),
                     ).await.ok();
                 }
             }
+            "netlify" => {
+                if let Some(netlify) = NetlifyDeployments::from_integration(integration) {
+                    metrics.netlify = fetch_netlify_metrics(
+                        &client,
+                        &netlify,
+                        deployment_window_days(integration),
+                    ).await.ok();
+                }
+            }
+            "railway" => {
+                if let Some(railway) = RailwayDeployments::from_integration(integration) {
+                    if let Ok(hosting) = fetch_railway_metrics(
+                        &client,
+                        &railway,
+                        deployment_window_days(integration),
+                    ).await {
+                        metrics.hosting.get_or_insert_with(Vec::new).push(hosting);
+                    }
+                }
+            }
+            "render" => {
+                if let Some(render) = RenderDeployments::from_integration(integration) {
+                    if let Ok(hosting) = fetch_render_metrics(
+                        &client,
+                        &render,
+                        deployment_window_days(integration),
+                    ).await {
+                        metrics.hosting.get_or_insert_with(Vec::new).push(hosting);
+                    }
+                }
+            }
+            "flyio" => {
+                if let Some(fly) = FlyDeployments::from_integration(integration) {
+                    if let Ok(hosting) = fetch_flyio_metrics(
+                        &client,
+                        &fly,
+                        deployment_window_days(integration),
+                    ).await {
+                        metrics.hosting.get_or_insert_with(Vec::new).push(hosting);
+                    }
+                }
+            }
             "posthog" => {
                 if let (Some(api_key), Some(project_id)) =
                     (&integration.api_key, &integration.project_id)
@@ -449,19 +1383,214 @@ async fn fetch_app_metrics(app: String) -> Result<String, String> {
                     metrics.posthog =
                         fetch_posthog_metrics(&client, api_key, project_id).await.ok();
                 }
+                if let Some(web_analytics) = fetch_web_analytics(&client, integration).await {
+                    metrics.web_analytics.get_or_insert_with(Vec::new).push(web_analytics);
+                }
             }
             "supabase" => {
                 if let (Some(api_key), Some(project_id)) =
                     (&integration.api_key, &integration.project_id)
                 {
-                    metrics.supabase =
-                        fetch_supabase_metrics(&client, api_key, project_id).await.ok();
+                    if let Ok((supabase, users)) = fetch_supabase_metrics(
+                        &client,
+                        api_key,
+                        project_id,
+                        integration.api_secret.as_deref(),
+                    ).await {
+                        metrics.supabase = Some(supabase);
+                        metrics.users.get_or_insert_with(Vec::new).push(users);
+                    }
+                }
+            }
+            "firebase" => {
+                if let Some(service_account) = &integration.api_key {
+                    if let Ok((firebase, users)) = fetch_firebase_metrics(
+                        &client,
+                        service_account,
+                        integration.project_id.as_deref(),
+                        integration.collections.as_deref().unwrap_or_default(),
+                    ).await {
+                        metrics.firebase = Some(firebase);
+                        metrics.users.get_or_insert_with(Vec::new).push(users);
+                    }
+                }
+            }
+            "clerk" => {
+                if let Some(secret_key) = &integration.api_key {
+                    if let Ok(users) = fetch_clerk_user_growth(&client, secret_key).await {
+                        metrics.users.get_or_insert_with(Vec::new).push(users);
+                    }
+                }
+            }
+            "auth0" => {
+                if let (Some(api_key), Some(domain)) =
+                    (&integration.api_key, &integration.project_id)
+                {
+                    if let Ok(users) = fetch_auth0_user_growth(
+                        &client,
+                        domain,
+                        api_key,
+                        integration.api_secret.as_deref(),
+                    ).await {
+                        metrics.users.get_or_insert_with(Vec::new).push(users);
+                    }
+                }
+            }
+            "sentry" => {
+                if let (Some(auth_token), Some(organization), Some(project)) =
+                    (&integration.api_key, &integration.team_id, &integration.project_id)
+                {
+                    metrics.sentry = fetch_sentry_metrics(
+                        &client,
+                        auth_token,
+                        organization,
+                        project,
+                        integration.base_url.as_deref(),
+                    ).await.ok();
+                }
+            }
+            "resend" => {
+                if let Some(api_key) = &integration.api_key {
+                    if let Ok(email) = fetch_resend_metrics(&client, api_key).await {
+                        metrics.email.get_or_insert_with(Vec::new).push(email);
+                    }
+                }
+            }
+            "sendgrid" => {
+                if let Some(api_key) = &integration.api_key {
+                    if let Ok(email) = fetch_sendgrid_metrics(&client, api_key).await {
+                        metrics.email.get_or_insert_with(Vec::new).push(email);
+                    }
+                }
+            }
+            "convertkit" => {
+                if let Some(api_secret) = &integration.api_key {
+                    if let Ok(newsletter) = fetch_convertkit_metrics(&client, api_secret).await {
+                        metrics.newsletter.get_or_insert_with(Vec::new).push(newsletter);
+                    }
+                }
+            }
+            "mailchimp" => {
+                if let Some(api_key) = &integration.api_key {
+                    if let Ok(newsletter) = fetch_mailchimp_metrics(
+                        &client,
+                        api_key,
+                        integration.project_id.as_deref(),
+                    ).await {
+                        metrics.newsletter.get_or_insert_with(Vec::new).push(newsletter);
+                    }
+                }
+            }
+            "intercom" => {
+                if let Some(access_token) = &integration.api_key {
+                    if let Ok(support) = fetch_intercom_metrics(&client, access_token).await {
+                        metrics.support.get_or_insert_with(Vec::new).push(support);
+                    }
+                }
+            }
+            "crisp" => {
+                if let (Some(identifier), Some(key), Some(website_id)) =
+                    (&integration.api_key, &integration.api_secret, &integration.project_id)
+                {
+                    if let Ok(support) = fetch_crisp_metrics(&client, identifier, key, website_id).await {
+                        metrics.support.get_or_insert_with(Vec::new).push(support);
+                    }
+                }
+            }
+            "discord" => {
+                if let (Some(bot_token), Some(guild_id)) = (&integration.api_key, &integration.team_id) {
+                    metrics.community = fetch_discord_metrics(
+                        &client,
+                        bot_token,
+                        guild_id,
+                        integration.base_url.as_deref(),
+                    ).await.ok();
+                }
+            }
+            "cal" => {
+                if let Some(api_key) = &integration.api_key {
+                    if let Ok((bookings, events)) =
+                        fetch_cal_bookings(&client, api_key, integration.base_url.as_deref()).await
+                    {
+                        metrics.bookings = Some(bookings);
+                        metrics.calendar_events.get_or_insert_with(Vec::new).extend(events);
+                    }
+                }
+            }
+            "logrocket" => {
+                if let (Some(api_key), Some(organization), Some(app), Some(metric_ids)) = (
+                    &integration.api_key,
+                    &integration.team_id,
+                    &integration.project_id,
+                    &integration.metric_ids,
+                ) {
+                    match fetch_logrocket_metrics(&client, api_key, organization, app, metric_ids).await {
+                        Ok(logrocket) => metrics.logrocket = Some(logrocket),
+                        Err(e) => println!("LogRocket fetch failed: {}", e),
+                    }
+                }
+            }
+            "planetscale" | "neon" | "mongodb" => {
+                if let Some(database) = fetch_database_health(&client, integration).await {
+                    metrics.database.get_or_insert_with(Vec::new).push(database);
+                }
+            }
+            "postgres" => {
+                if let (Some(connection_string), Some(queries)) =
+                    (&integration.api_key, &integration.queries)
+                {
+                    if let Ok(results) = fetch_custom_sql_metrics(connection_string, queries).await {
+                        metrics.custom_metrics.get_or_insert_with(Vec::new).extend(results);
+                    }
+                }
+            }
+            "amplitude" => {
+                if let (Some(api_key), Some(api_secret)) =
+                    (&integration.api_key, &integration.api_secret)
+                {
+                    metrics.amplitude = fetch_amplitude_metrics(
+                        &client,
+                        api_key,
+                        api_secret,
+                        integration.event_name.as_deref(),
+                        integration.base_url.as_deref(),
+                    ).await.ok();
+                }
+            }
+            "plausible" | "umami" | "fathom" => {
+                if let Some(web_analytics) = fetch_web_analytics(&client, integration).await {
+                    metrics.web_analytics.get_or_insert_with(Vec::new).push(web_analytics);
+                }
+            }
+            "google_analytics" => {
+                // GA4 authenticates with the project's Google OAuth connection
+                if let Some(google) = &app.google_calendar {
+                    let integration = Integration {
+                        api_key: google.access_token.clone(),
+                        api_secret: google.refresh_token.clone(),
+                        ..integration.clone()
+                    };
+                    if let Some(analytics) = GoogleAnalytics::from_integration(&integration) {
+                        match analytics.fetch_with_refresh(&client).await {
+                            Ok((web_analytics, refreshed_token)) => {
+                                metrics.web_analytics.get_or_insert_with(Vec::new).push(web_analytics);
+                                metrics.google_access_token = refreshed_token;
+                            }
+                            Err(e) => println!("google_analytics web analytics error: {}", e),
+                        }
+                    }
                 }
             }
             _ => {}
         }
     }
 
+    metrics.deploy_timeline = build_deploy_timeline(&metrics);
+    metrics.deploy_activity = metrics
+        .deploy_timeline
+        .as_ref()
+        .map(|timeline| deployment_activity(&timeline.deployments));
+
     serde_json::to_string(&metrics).map_err(|e| e.to_string())
 }
 
@@ -953,510 +2082,6712 @@ async fn fetch_stripe_events(
 }
 
 // ==========================================
-// Vercel API Functions
+// Deployment Providers
 // ==========================================
 
-async fn fetch_vercel_metrics(
-    client: &reqwest::Client,
-    api_key: &str,
-    project_id: &str,
-    team_id: Option<&str>,
-) -> Result<VercelMetrics, String> {
-    // Build URL with optional teamId
-    let url = match team_id {
-        Some(team) => format!(
-            "https://api.vercel.com/v6/deployments?projectId={}&teamId={}&limit=5",
-            project_id, team
-        ),
-        None => format!(
-            "https://api.vercel.com/v6/deployments?projectId={}&limit=5",
-            project_id
-        ),
-    };
+// Implemented by every host so their deploys land in one normalised timeline.
+trait DeploymentProvider: Sized {
+    fn from_integration(integration: &Integration) -> Option<Self>;
 
-    let response = client
-        .get(&url)
-        .bearer_auth(api_key)
-        .send()
-        .await
-        .map_err(|e| e.to_string())?;
+    // Deployments created since `since`, newest first
+    async fn fetch_deployments(
+        &self,
+        client: &reqwest::Client,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Deployment>, String>;
+}
 
-    let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+const DEFAULT_DEPLOYMENT_WINDOW_DAYS: u32 = 30;
+const MAX_DEPLOYMENT_WINDOW_DAYS: u32 = 90;
 
-    let empty_deployments = vec![];
-    let deployments: Vec<Deployment> = data["deployments"]
-        .as_array()
-        .unwrap_or(&empty_deployments)
+// Days of deployment history set in Settings, kept within what paging can cover
+fn deployment_window_days(integration: &Integration) -> u32 {
+    integration
+        .history_days
+        .unwrap_or(DEFAULT_DEPLOYMENT_WINDOW_DAYS)
+        .clamp(1, MAX_DEPLOYMENT_WINDOW_DAYS)
+}
+
+fn deployment_window_start(window_days: u32) -> chrono::DateTime<chrono::Utc> {
+    chrono::Utc::now() - chrono::Duration::days(window_days as i64)
+}
+
+fn parse_timestamp(value: &serde_json::Value) -> Option<chrono::DateTime<chrono::Utc>> {
+    match value {
+        serde_json::Value::Number(ms) => ms.as_i64().and_then(chrono::DateTime::from_timestamp_millis),
+        serde_json::Value::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|dt| dt.with_timezone(&chrono::Utc)),
+        _ => None,
+    }
+}
+
+fn duration_between(
+    started: Option<chrono::DateTime<chrono::Utc>>,
+    finished: Option<chrono::DateTime<chrono::Utc>>,
+) -> Option<i64> {
+    match (started, finished) {
+        (Some(start), Some(end)) if end >= start => Some((end - start).num_seconds()),
+        _ => None,
+    }
+}
+
+// Deployments are expected newest first
+fn summarize_deployments(deployments: Vec<Deployment>, window_days: u32) -> DeploymentHistory {
+    let count_state = |state: DeploymentState| {
+        deployments.iter().filter(|d| d.state == state).count() as i32
+    };
+    let ready_deployments = count_state(DeploymentState::Ready);
+    let failed_deployments = count_state(DeploymentState::Error);
+    let canceled_deployments = count_state(DeploymentState::Canceled);
+
+    let attempted = deployments.iter().filter(|d| d.state.is_attempt()).count() as i32;
+    let success_rate = if attempted > 0 {
+        (ready_deployments as f64 / attempted as f64) * 100.0
+    } else {
+        100.0
+    };
+
+    let build_times: Vec<i64> = deployments
         .iter()
-        .take(5)
-        .filter_map(|d| {
-            Some(Deployment {
-                id: d["uid"].as_str()?.to_string(),
-                name: d["name"].as_str()?.to_string(),
-                state: d["state"].as_str().unwrap_or("unknown").to_string(),
-                created_at: d["createdAt"].to_string(),
-                url: d["url"].as_str().unwrap_or("").to_string(),
-            })
-        })
+        .filter(|d| d.state.is_attempt())
+        .filter_map(|d| d.duration)
         .collect();
+    let average_build_time = if build_times.is_empty() {
+        0.0
+    } else {
+        build_times.iter().sum::<i64>() as f64 / build_times.len() as f64
+    };
+
+    let production_deployments = deployments
+        .iter()
+        .filter(|d| d.environment == "production")
+        .count() as i32;
+    let preview_deployments = deployments.len() as i32 - production_deployments;
 
-    let latest_status = deployments
+    let status = deployments
         .first()
-        .map(|d| d.state.clone())
-        .unwrap_or_else(|| "unknown".to_string());
+        .map(|d| d.state.status())
+        .unwrap_or("unknown")
+        .to_string();
 
     let last_deployed_at = deployments.first().map(|d| d.created_at.clone());
 
-    Ok(VercelMetrics {
+    DeploymentHistory {
+        total_deployments: deployments.len() as i32,
         deployments,
         last_deployed_at,
-        status: latest_status,
-    })
+        status,
+        window_days,
+        failed_deployments,
+        canceled_deployments,
+        production_deployments,
+        preview_deployments,
+        success_rate,
+        average_build_time,
+    }
 }
 
-// ==========================================
-// PostHog API Functions
-// ==========================================
+// Merges every configured host's deployments into a single newest-first timeline
+fn build_deploy_timeline(metrics: &AppMetrics) -> Option<DeploymentHistory> {
+    let histories: Vec<&DeploymentHistory> = [
+        metrics.vercel.as_ref().map(|v| &v.history),
+        metrics.netlify.as_ref().map(|n| &n.history),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(metrics.hosting.iter().flatten().map(|h| &h.history))
+    .collect();
+
+    if histories.is_empty() {
+        return None;
+    }
 
-async fn fetch_posthog_metrics(
-    client: &reqwest::Client,
-    api_key: &str,
-    project_id: &str,
-) -> Result<PostHogMetrics, String> {
-    // PostHog uses a different API structure - this is a simplified version
-    let response = client
-        .get(format!(
-            "https://app.posthog.com/api/projects/{}/insights/trend/",
-            project_id
-        ))
-        .header("Authorization", format!("Bearer {}", api_key))
-        .send()
-        .await
-        .map_err(|e| e.to_string())?;
+    let window_days = histories.iter().map(|h| h.window_days).max().unwrap_or(30);
+    let mut deployments: Vec<Deployment> = histories
+        .iter()
+        .flat_map(|h| h.deployments.iter().cloned())
+        .collect();
+    deployments.sort_by_key(|d| {
+        std::cmp::Reverse(chrono::DateTime::parse_from_rfc3339(&d.created_at).ok())
+    });
 
-    if response.status().is_success() {
-        // For now, return placeholder data - real implementation would parse the response
-        Ok(PostHogMetrics {
-            total_events_24h: 0,
-            unique_users_24h: 0,
-            total_events_7d: 0,
-            unique_users_7d: 0,
+    Some(summarize_deployments(deployments, window_days))
+}
+
+fn deployment_activity(deployments: &[Deployment]) -> Vec<DeploymentActivity> {
+    deployments
+        .iter()
+        .filter(|d| d.state.is_attempt())
+        .map(|d| {
+            let is_success = d.state == DeploymentState::Ready;
+            let timestamp = d
+                .finished_at
+                .as_deref()
+                .unwrap_or(&d.created_at)
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .map(|dt| dt.timestamp())
+                .unwrap_or(0);
+
+            DeploymentActivity {
+                id: d.id.clone(),
+                source: d.provider.clone(),
+                activity_type: if is_success { "deployment_success" } else { "deployment_failed" }.to_string(),
+                timestamp,
+                title: if is_success { "Deployment successful" } else { "Deployment failed" }.to_string(),
+                description: Some(match &d.commit_message {
+                    Some(message) => format!("{} ({}): {}", d.name, d.environment, message),
+                    None => format!("{} ({})", d.name, d.environment),
+                }),
+                deployment_url: d.url.clone(),
+                is_positive: is_success,
+            }
         })
+        .collect()
+}
+
+// Healthy when the latest attempted deploy succeeded and every instance is up
+fn hosting_health(history: &DeploymentHistory, running: i32, total: i32) -> String {
+    let latest_attempt = history
+        .deployments
+        .iter()
+        .find(|d| d.state.is_attempt())
+        .map(|d| d.state);
+    let health = if total > 0 && running == 0 {
+        "down"
+    } else if latest_attempt == Some(DeploymentState::Error) || running < total {
+        "degraded"
+    } else if latest_attempt == Some(DeploymentState::Ready) || running > 0 {
+        "healthy"
     } else {
-        Err("Failed to fetch PostHog metrics".to_string())
-    }
+        "unknown"
+    };
+    health.to_string()
 }
 
 // ==========================================
-// Supabase API Functions
+// Vercel API Functions
 // ==========================================
 
-async fn fetch_supabase_metrics(
-    client: &reqwest::Client,
-    api_key: &str,  // This should be the service_role key
-    project_id: &str,  // This is the project ref (e.g., "xyzcompany")
-) -> Result<SupabaseMetrics, String> {
-    // Use Supabase Auth Admin API to list users
-    // The project_id should be the project reference (e.g., "abcdefghijklmnop")
-    // API key should be the service_role key
-    let auth_url = format!(
-        "https://{}.supabase.co/auth/v1/admin/users",
-        project_id
-    );
+struct VercelDeployments {
+    api_key: String,
+    project_id: String,
+    team_id: Option<String>,
+}
 
-    println!("Fetching Supabase users from: {}", auth_url);
+fn normalize_vercel_state(state: &str) -> DeploymentState {
+    match state {
+        "READY" => DeploymentState::Ready,
+        "ERROR" => DeploymentState::Error,
+        "CANCELED" => DeploymentState::Canceled,
+        "QUEUED" | "INITIALIZING" => DeploymentState::Queued,
+        "BUILDING" => DeploymentState::Building,
+        _ => DeploymentState::Unknown,
+    }
+}
 
-    let response = client
-        .get(&auth_url)
-        .header("apikey", api_key)
-        .header("Authorization", format!("Bearer {}", api_key))
-        .query(&[("page", "1"), ("per_page", "1000")])
-        .send()
-        .await
-        .map_err(|e| e.to_string())?;
+impl DeploymentProvider for VercelDeployments {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(VercelDeployments {
+            api_key: integration.api_key.clone()?,
+            project_id: integration.project_id.clone()?,
+            team_id: integration.team_id.clone(),
+        })
+    }
 
-    let status = response.status();
-    println!("Supabase response status: {}", status);
+    async fn fetch_deployments(
+        &self,
+        client: &reqwest::Client,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Deployment>, String> {
+        let since = since.timestamp_millis();
+
+        // Page backwards through the deployment list using pagination.next as the `until` cursor
+        let mut raw_deployments: Vec<serde_json::Value> = Vec::new();
+        let mut until: Option<i64> = None;
+
+        for _ in 0..20 {
+            let mut query = vercel_team_query(self.team_id.as_deref());
+            query.push(("projectId", self.project_id.clone()));
+            query.push(("since", since.to_string()));
+            query.push(("limit", "100".to_string()));
+            if let Some(cursor) = until {
+                query.push(("until", cursor.to_string()));
+            }
 
-    if status.is_success() {
-        let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+            let response = client
+                .get("https://api.vercel.com/v6/deployments")
+                .query(&query)
+                .bearer_auth(&self.api_key)
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
 
-        // The response has { users: [...], aud: "...", ... }
-        let users = data["users"].as_array();
-        let total_users = users.map(|u| u.len() as i32).unwrap_or(0);
-
-        // Count users created in last 7 days
-        let seven_days_ago = chrono::Utc::now() - chrono::Duration::days(7);
-        let new_users_7d = users
-            .map(|users_arr| {
-                users_arr
-                    .iter()
-                    .filter(|user| {
-                        user["created_at"]
-                            .as_str()
-                            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                            .map(|dt| dt > seven_days_ago)
-                            .unwrap_or(false)
-                    })
-                    .count() as i32
-            })
-            .unwrap_or(0);
+            let status = response.status();
+            if !status.is_success() {
+                let error_text = response.text().await.unwrap_or_default();
+                println!("Vercel error: {}", error_text);
+                return Err(format!("Failed to fetch Vercel deployments: {}", status));
+            }
 
-        println!("Found {} total users, {} new in last 7 days", total_users, new_users_7d);
+            let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+            if let Some(page) = data["deployments"].as_array() {
+                raw_deployments.extend(page.iter().cloned());
+            }
 
-        Ok(SupabaseMetrics {
-            total_users,
-            new_users_7d,
-            database_size: "N/A".to_string(),
-            api_requests_24h: 0,
-        })
-    } else {
-        let error_text = response.text().await.unwrap_or_default();
-        println!("Supabase error: {}", error_text);
-        Err(format!("Failed to fetch Supabase metrics: {}", status))
+            match data["pagination"]["next"].as_i64() {
+                Some(next) if next >= since => until = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(raw_deployments
+            .iter()
+            .filter(|d| d["createdAt"].as_i64().unwrap_or(0) >= since)
+            .filter_map(|d| {
+                let created = parse_timestamp(&d["createdAt"])?;
+                let state = normalize_vercel_state(
+                    d["state"].as_str().or_else(|| d["readyState"].as_str()).unwrap_or("unknown"),
+                );
+                let started = parse_timestamp(&d["buildingAt"]);
+                let finished = parse_timestamp(&d["ready"]).filter(|_| state.is_finished());
+
+                // Git metadata lives under meta.githubCommit* / gitlabCommit* / bitbucketCommit*
+                let meta = &d["meta"];
+                let git_field = |suffix: &str| {
+                    ["github", "gitlab", "bitbucket"]
+                        .iter()
+                        .find_map(|host| meta[format!("{}Commit{}", host, suffix)].as_str())
+                        .map(|s| s.to_string())
+                };
+
+                Some(Deployment {
+                    id: d["uid"].as_str()?.to_string(),
+                    provider: "vercel".to_string(),
+                    name: d["name"].as_str()?.to_string(),
+                    // Vercel leaves target null for preview deployments
+                    environment: d["target"].as_str().unwrap_or("preview").to_string(),
+                    state,
+                    created_at: created.to_rfc3339(),
+                    started_at: started.map(|dt| dt.to_rfc3339()),
+                    finished_at: finished.map(|dt| dt.to_rfc3339()),
+                    duration: duration_between(started, finished),
+                    url: d["url"].as_str().unwrap_or("").to_string(),
+                    commit_sha: git_field("Sha"),
+                    commit_message: git_field("Message"),
+                    commit_author: git_field("AuthorName"),
+                })
+            })
+            .collect())
     }
 }
 
-// ==========================================
-// Google Calendar OAuth
-// ==========================================
-//
-// TODO: For production/indie release, implement "Bring Your Own Credentials" feature:
-// - Let users provide their own Google OAuth Client ID & Secret in Settings
-// - Pass credentials from frontend to these functions instead of using hardcoded values
-// - This avoids Google's app verification process and scales infinitely
-// - Current setup requires manually adding test users in Google Cloud Console
+async fn fetch_vercel_metrics(
+    client: &reqwest::Client,
+    vercel: &VercelDeployments,
+    window_days: u32,
+) -> Result<VercelMetrics, String> {
+    let deployments = vercel
+        .fetch_deployments(client, deployment_window_start(window_days))
+        .await?;
+    let history = summarize_deployments(deployments, window_days);
+
+    println!(
+        "Vercel: {} deployments in {} days ({} failed, {} canceled), {:.1}% success",
+        history.total_deployments,
+        window_days,
+        history.failed_deployments,
+        history.canceled_deployments,
+        history.success_rate
+    );
 
-// These are embedded at compile time from environment variables
-// For local dev: create .env file with GOOGLE_CLIENT_ID and GOOGLE_CLIENT_SECRET
-// For CI builds: set as GitHub Actions secrets
-const GOOGLE_CLIENT_ID: &str = env!("GOOGLE_CLIENT_ID");
-const GOOGLE_CLIENT_SECRET: &str = env!("GOOGLE_CLIENT_SECRET");
+    let team_id = vercel.team_id.as_deref();
+    let domains = fetch_vercel_domains(client, &vercel.api_key, &vercel.project_id, team_id)
+        .await
+        .unwrap_or_default();
+    let usage = fetch_vercel_usage(client, &vercel.api_key, team_id).await;
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GoogleCalendarEvent {
-    id: String,
-    summary: Option<String>,
-    start: Option<GoogleEventTime>,
-    end: Option<GoogleEventTime>,
-    description: Option<String>,
-    #[serde(rename = "htmlLink")]
-    html_link: Option<String>,
+    let mut recent_failures = Vec::new();
+    for deployment in history
+        .deployments
+        .iter()
+        .filter(|d| d.state == DeploymentState::Error)
+        .take(5)
+    {
+        recent_failures.push(fetch_vercel_failure(client, &vercel.api_key, team_id, deployment).await);
+    }
+
+    Ok(VercelMetrics {
+        history,
+        domains,
+        usage,
+        recent_failures,
+    })
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GoogleEventTime {
-    #[serde(rename = "dateTime")]
-    date_time: Option<String>,
-    date: Option<String>,
+fn vercel_team_query(team_id: Option<&str>) -> Vec<(&'static str, String)> {
+    team_id
+        .map(|team| vec![("teamId", team.to_string())])
+        .unwrap_or_default()
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GoogleCalendarList {
-    items: Option<Vec<GoogleCalendarEvent>>,
+// Each domain needs its own config request, so only the first few are checked
+const VERCEL_DOMAIN_CONFIG_LIMIT: usize = 10;
+
+const SSL_CHECK_TIMEOUT_SECS: u64 = 5;
+
+// TLS client trusting the public web roots, shared by Postgres and the SSL check
+fn tls_client_config() -> Result<rustls::ClientConfig, String> {
+    let mut roots = rustls::RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    Ok(rustls::ClientConfig::builder_with_provider(std::sync::Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .map_err(|e| e.to_string())?
+    .with_root_certificates(roots)
+    .with_no_client_auth())
 }
 
-// Loopback OAuth flow - opens browser, waits for callback, returns tokens
-#[tauri::command]
-async fn start_google_oauth() -> Result<String, String> {
-    use tokio::net::TcpListener;
-    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+// Splits one DER element into (tag, contents, remaining bytes)
+fn der_element(bytes: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = bytes.split_first()?;
+    let (&first, rest) = rest.split_first()?;
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        let size = (first & 0x7f) as usize;
+        if size == 0 || size > 4 || rest.len() < size {
+            return None;
+        }
+        let len = rest[..size].iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+        (len, &rest[size..])
+    };
+    if rest.len() < len {
+        return None;
+    }
+    Some((tag, &rest[..len], &rest[len..]))
+}
 
-    // Bind to a random available port on localhost
-    let listener = TcpListener::bind("127.0.0.1:0")
-        .await
-        .map_err(|e| format!("Failed to bind: {}", e))?;
+// Reads notAfter from an X.509 certificate's validity period
+fn certificate_not_after(der: &[u8]) -> Option<chrono::DateTime<chrono::Utc>> {
+    let (_, certificate, _) = der_element(der)?;
+    let (_, mut tbs, _) = der_element(certificate)?;
+    // Skip the optional [0] version, then the serial number, signature algorithm and issuer
+    if tbs.first() == Some(&0xa0) {
+        tbs = der_element(tbs)?.2;
+    }
+    for _ in 0..3 {
+        tbs = der_element(tbs)?.2;
+    }
+    let (_, validity, _) = der_element(tbs)?;
+    let (_, _, validity) = der_element(validity)?;
+    let (tag, not_after, _) = der_element(validity)?;
+
+    let format = match tag {
+        0x17 => "%y%m%d%H%M%SZ", // UTCTime
+        0x18 => "%Y%m%d%H%M%SZ", // GeneralizedTime
+        _ => return None,
+    };
+    let text = std::str::from_utf8(not_after).ok()?;
+    chrono::NaiveDateTime::parse_from_str(text, format)
+        .ok()
+        .map(|dt| dt.and_utc())
+}
 
-    let port = listener.local_addr()
-        .map_err(|e| format!("Failed to get local addr: {}", e))?
-        .port();
+// Vercel's API has no certificate lookup by domain, so the certificate is read
+// from a TLS handshake with the domain itself. Blocking; run it off the runtime.
+fn check_ssl_certificate(domain: &str) -> Option<SslCertificate> {
+    let config = tls_client_config().ok()?;
+    let server_name = rustls::pki_types::ServerName::try_from(domain.to_string()).ok()?;
+    let mut connection = rustls::ClientConnection::new(std::sync::Arc::new(config), server_name).ok()?;
+
+    let timeout = std::time::Duration::from_secs(SSL_CHECK_TIMEOUT_SECS);
+    let address = std::net::ToSocketAddrs::to_socket_addrs(&(domain, 443)).ok()?.next()?;
+    let mut socket = std::net::TcpStream::connect_timeout(&address, timeout).ok()?;
+    socket.set_read_timeout(Some(timeout)).ok()?;
+    socket.set_write_timeout(Some(timeout)).ok()?;
+
+    while connection.is_handshaking() {
+        if let Err(e) = connection.complete_io(&mut socket) {
+            // An expired, untrusted or mismatched certificate is a result; anything else means we couldn't tell
+            let rejected = e
+                .get_ref()
+                .and_then(|inner| inner.downcast_ref::<rustls::Error>())
+                .is_some_and(|tls_error| matches!(tls_error, rustls::Error::InvalidCertificate(_)));
+            return rejected.then_some(SslCertificate {
+                valid: false,
+                expires_at: None,
+                days_remaining: None,
+            });
+        }
+    }
 
-    let redirect_uri = format!("http://127.0.0.1:{}", port);
-    // Request both Calendar and Gmail scopes
-    let scopes = "https://www.googleapis.com/auth/calendar.readonly https://www.googleapis.com/auth/gmail.readonly";
+    let expires = connection
+        .peer_certificates()
+        .and_then(|chain| chain.first())
+        .and_then(|leaf| certificate_not_after(leaf.as_ref()));
 
-    // Build the auth URL
-    let auth_url = format!(
-        "https://accounts.google.com/o/oauth2/v2/auth?client_id={}&redirect_uri={}&response_type=code&scope={}&access_type=offline&prompt=consent",
-        GOOGLE_CLIENT_ID,
-        urlencoding::encode(&redirect_uri),
-        urlencoding::encode(scopes)
-    );
+    Some(SslCertificate {
+        valid: true,
+        expires_at: expires.map(|dt| dt.to_rfc3339()),
+        days_remaining: expires.map(|dt| (dt - chrono::Utc::now()).num_days()),
+    })
+}
 
-    // Open the browser
-    if let Err(e) = open::that(&auth_url) {
-        return Err(format!("Failed to open browser: {}", e));
+async fn fetch_vercel_domains(
+    client: &reqwest::Client,
+    api_key: &str,
+    project_id: &str,
+    team_id: Option<&str>,
+) -> Result<Vec<VercelDomain>, String> {
+    let response = client
+        .get(format!("https://api.vercel.com/v9/projects/{}/domains", project_id))
+        .query(&vercel_team_query(team_id))
+        .bearer_auth(api_key)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch Vercel domains: {}", response.status()));
     }
 
-    // Wait for the OAuth callback (with 5 minute timeout)
-    let code = tokio::time::timeout(
-        std::time::Duration::from_secs(300),
-        async {
-            let (mut socket, _) = listener.accept()
-                .await
-                .map_err(|e| format!("Failed to accept connection: {}", e))?;
+    let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    let empty_vec = vec![];
 
-            let mut buffer = vec![0u8; 4096];
-            let n = socket.read(&mut buffer)
+    let mut domains = Vec::new();
+    for domain in data["domains"].as_array().unwrap_or(&empty_vec) {
+        let Some(name) = domain["name"].as_str() else {
+            continue;
+        };
+
+        let checked = domains.len() < VERCEL_DOMAIN_CONFIG_LIMIT;
+        let ssl_check = checked.then(|| {
+            let name = name.to_string();
+            tokio::task::spawn_blocking(move || check_ssl_certificate(&name))
+        });
+
+        // The config endpoint reports whether DNS points at Vercel
+        let misconfigured = if checked {
+            match client
+                .get(format!("https://api.vercel.com/v6/domains/{}/config", name))
+                .query(&vercel_team_query(team_id))
+                .bearer_auth(api_key)
+                .send()
                 .await
-                .map_err(|e| format!("Failed to read: {}", e))?;
+            {
+                Ok(resp) if resp.status().is_success() => resp
+                    .json::<serde_json::Value>()
+                    .await
+                    .ok()
+                    .and_then(|config| config["misconfigured"].as_bool()),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        domains.push(VercelDomain {
+            name: name.to_string(),
+            verified: domain["verified"].as_bool().unwrap_or(false),
+            misconfigured,
+            redirect: domain["redirect"].as_str().map(|s| s.to_string()),
+            git_branch: domain["gitBranch"].as_str().map(|s| s.to_string()),
+            ssl: match ssl_check {
+                Some(task) => task.await.ok().flatten(),
+                None => None,
+            },
+        });
+    }
 
-            let request = String::from_utf8_lossy(&buffer[..n]);
+    Ok(domains)
+}
 
-            // Parse the code from the request
-            let code = request
-                .lines()
-                .next()
-                .and_then(|line| line.split_whitespace().nth(1))
-                .and_then(|path| {
-                    if path.starts_with("/?code=") {
-                        path.strip_prefix("/?code=")
-                            .and_then(|s| s.split('&').next())
-                            .map(|s| urlencoding::decode(s).unwrap_or_default().to_string())
-                    } else if path.contains("code=") {
-                        path.split("code=")
-                            .nth(1)
-                            .and_then(|s| s.split('&').next())
-                            .map(|s| urlencoding::decode(s).unwrap_or_default().to_string())
-                    } else {
-                        None
-                    }
-                })
-                .ok_or_else(|| "No authorization code found in callback".to_string())?;
+async fn fetch_vercel_usage(
+    client: &reqwest::Client,
+    api_key: &str,
+    team_id: Option<&str>,
+) -> Option<VercelUsage> {
+    // Billing periods run per calendar month unless the team reports otherwise
+    let now = chrono::Utc::now();
+    let mut period_start = now
+        .with_day(1)
+        .and_then(|d| d.with_hour(0))
+        .and_then(|d| d.with_minute(0))
+        .and_then(|d| d.with_second(0))
+        .unwrap_or(now);
+    let mut period_end = now;
+
+    if let Some(team) = team_id {
+        if let Ok(resp) = client
+            .get(format!("https://api.vercel.com/v2/teams/{}", team))
+            .bearer_auth(api_key)
+            .send()
+            .await
+        {
+            if let Ok(data) = resp.json::<serde_json::Value>().await {
+                let period = &data["billing"]["period"];
+                if let (Some(start), Some(end)) = (
+                    period["start"].as_i64().and_then(chrono::DateTime::from_timestamp_millis),
+                    period["end"].as_i64().and_then(chrono::DateTime::from_timestamp_millis),
+                ) {
+                    period_start = start;
+                    period_end = end;
+                }
+            }
+        }
+    }
 
-            // Send a success response to the browser
-            let response = "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n\
-                <html><body style=\"font-family: system-ui; text-align: center; padding: 50px;\">\
-                <h1>Authorization Successful!</h1>\
-                <p>You can close this window and return to Pulse.</p>\
-                <script>window.close();</script>\
-                </body></html>";
+    // Usage isn't part of the documented REST API on every plan, so treat it as optional
+    let mut query = vercel_team_query(team_id);
+    query.push(("from", period_start.to_rfc3339()));
+    query.push(("to", now.to_rfc3339()));
 
-            let _ = socket.write_all(response.as_bytes()).await;
-            let _ = socket.flush().await;
+    let usage: serde_json::Value = match client
+        .get("https://api.vercel.com/v2/usage")
+        .query(&query)
+        .bearer_auth(api_key)
+        .send()
+        .await
+    {
+        Ok(resp) if resp.status().is_success() => resp.json().await.ok()?,
+        Ok(resp) => {
+            println!("Vercel usage unavailable: {}", resp.status());
+            return None;
+        }
+        Err(_) => return None,
+    };
 
-            Ok::<String, String>(code)
+    let usage_value = |keys: &[&str]| -> Option<i64> {
+        keys.iter().find_map(|key| {
+            let value = &usage[*key];
+            value.as_i64().or_else(|| value["value"].as_i64()).or_else(|| value["total"].as_i64())
+        })
+    };
+
+    let bandwidth_bytes = usage_value(&["bandwidth", "bandwidthBytes"]);
+    let function_invocations = usage_value(&["functionInvocations", "serverlessFunctionInvocations"]);
+    let build_minutes = usage_value(&["buildMinutes"]).map(|m| m as f64);
+    if bandwidth_bytes.is_none() && function_invocations.is_none() && build_minutes.is_none() {
+        return None;
+    }
+
+    Some(VercelUsage {
+        period_start: period_start.to_rfc3339(),
+        period_end: period_end.to_rfc3339(),
+        bandwidth_bytes,
+        function_invocations,
+        build_minutes,
+    })
+}
+
+async fn fetch_vercel_failure(
+    client: &reqwest::Client,
+    api_key: &str,
+    team_id: Option<&str>,
+    deployment: &Deployment,
+) -> FailedDeployment {
+    let mut error_code = None;
+    let mut error_message = None;
+
+    if let Ok(resp) = client
+        .get(format!("https://api.vercel.com/v13/deployments/{}", deployment.id))
+        .query(&vercel_team_query(team_id))
+        .bearer_auth(api_key)
+        .send()
+        .await
+    {
+        if let Ok(data) = resp.json::<serde_json::Value>().await {
+            error_code = data["errorCode"].as_str().map(|s| s.to_string());
+            error_message = data["errorMessage"].as_str().map(|s| s.to_string());
         }
-    )
-    .await
-    .map_err(|_| "OAuth timed out after 5 minutes".to_string())??;
+    }
 
-    // Exchange the code for tokens
-    let client = reqwest::Client::new();
-    let params = [
-        ("client_id", GOOGLE_CLIENT_ID),
-        ("client_secret", GOOGLE_CLIENT_SECRET),
-        ("code", code.as_str()),
-        ("grant_type", "authorization_code"),
-        ("redirect_uri", redirect_uri.as_str()),
-    ];
+    // Build events look like [{ type: "stderr", payload: { text: "..." } }, ...]
+    let mut query = vercel_team_query(team_id);
+    query.push(("builds", "1".to_string()));
+    query.push(("limit", "-1".to_string())); // -1 returns the full build log
 
-    let response = client
-        .post("https://oauth2.googleapis.com/token")
-        .form(&params)
+    let mut log_lines: Vec<String> = Vec::new();
+    if let Ok(resp) = client
+        .get(format!("https://api.vercel.com/v3/deployments/{}/events", deployment.id))
+        .query(&query)
+        .bearer_auth(api_key)
         .send()
         .await
-        .map_err(|e| format!("Failed to exchange code: {}", e))?;
+    {
+        if let Ok(events) = resp.json::<serde_json::Value>().await {
+            for event in events.as_array().into_iter().flatten() {
+                let text = event["payload"]["text"].as_str().or_else(|| event["text"].as_str());
+                let is_error = event["type"].as_str() == Some("stderr")
+                    || text.map(|t| t.to_lowercase().contains("error")).unwrap_or(false);
+                if let (true, Some(text)) = (is_error, text) {
+                    log_lines.push(text.trim_end().to_string());
+                }
+            }
+        }
+    }
 
-    let status = response.status();
-    let body = response.text().await.map_err(|e| e.to_string())?;
+    // Keep the tail of the log, which is where the build actually failed
+    if log_lines.len() > 20 {
+        log_lines = log_lines.split_off(log_lines.len() - 20);
+    }
 
-    if status.is_success() {
-        Ok(body)
-    } else {
-        Err(format!("Token exchange failed: {}", body))
+    FailedDeployment {
+        id: deployment.id.clone(),
+        url: deployment.url.clone(),
+        created_at: deployment.created_at.clone(),
+        commit_message: deployment.commit_message.clone(),
+        error_code,
+        error_message,
+        log_lines,
     }
 }
 
-#[tauri::command]
-async fn refresh_google_token(refresh_token: String) -> Result<String, String> {
-    let client = reqwest::Client::new();
+// ==========================================
+// Netlify API Functions
+// ==========================================
 
-    let params = [
-        ("client_id", GOOGLE_CLIENT_ID),
-        ("client_secret", GOOGLE_CLIENT_SECRET),
-        ("refresh_token", &refresh_token),
-        ("grant_type", "refresh_token"),
-    ];
+struct NetlifyDeployments {
+    api_key: String,
+    site_id: String,
+}
 
-    let response = client
-        .post("https://oauth2.googleapis.com/token")
-        .form(&params)
+fn normalize_netlify_state(state: &str) -> DeploymentState {
+    match state {
+        "ready" => DeploymentState::Ready,
+        "error" | "rejected" => DeploymentState::Error,
+        "canceled" | "cancelled" | "skipped" => DeploymentState::Canceled,
+        "new" | "enqueued" | "pending" | "accepted" => DeploymentState::Queued,
+        "building" | "uploading" | "uploaded" | "preparing" | "prepared" | "processing" | "processed" => {
+            DeploymentState::Building
+        }
+        _ => DeploymentState::Unknown,
+    }
+}
+
+fn parse_netlify_deploy(deploy: &serde_json::Value) -> Option<Deployment> {
+    let created = parse_timestamp(&deploy["created_at"])?;
+    let state = normalize_netlify_state(deploy["state"].as_str().unwrap_or("unknown"));
+    let finished = parse_timestamp(&deploy["published_at"])
+        .or_else(|| parse_timestamp(&deploy["updated_at"]))
+        .filter(|_| state.is_finished());
+    let context = deploy["context"].as_str().unwrap_or("production");
+
+    Some(Deployment {
+        id: deploy["id"].as_str()?.to_string(),
+        provider: "netlify".to_string(),
+        name: deploy["name"].as_str().unwrap_or_default().to_string(),
+        // deploy-preview and branch-deploy contexts are both previews
+        environment: if context == "production" { "production" } else { "preview" }.to_string(),
+        state,
+        created_at: created.to_rfc3339(),
+        started_at: Some(created.to_rfc3339()),
+        finished_at: finished.map(|dt| dt.to_rfc3339()),
+        duration: deploy["deploy_time"].as_i64().or_else(|| duration_between(Some(created), finished)),
+        url: deploy["deploy_ssl_url"]
+            .as_str()
+            .or_else(|| deploy["deploy_url"].as_str())
+            .unwrap_or_default()
+            .to_string(),
+        commit_sha: deploy["commit_ref"].as_str().map(|s| s.to_string()),
+        commit_message: deploy["title"].as_str().map(|s| s.to_string()),
+        commit_author: deploy["committer"].as_str().map(|s| s.to_string()),
+    })
+}
+
+impl DeploymentProvider for NetlifyDeployments {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(NetlifyDeployments {
+            api_key: integration.api_key.clone()?,
+            site_id: integration.project_id.clone()?,
+        })
+    }
+
+    async fn fetch_deployments(
+        &self,
+        client: &reqwest::Client,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Deployment>, String> {
+        let in_window = |d: &Deployment| {
+            chrono::DateTime::parse_from_rfc3339(&d.created_at)
+                .map(|dt| dt >= since)
+                .unwrap_or(false)
+        };
+
+        // Deploys come back newest first; stop paging once we're past the window
+        let mut deployments: Vec<Deployment> = Vec::new();
+        for page in 1..=20 {
+            let response = client
+                .get(format!("https://api.netlify.com/api/v1/sites/{}/deploys", self.site_id))
+                .query(&[("page", page.to_string()), ("per_page", "100".to_string())])
+                .bearer_auth(&self.api_key)
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let error_text = response.text().await.unwrap_or_default();
+                println!("Netlify error: {}", error_text);
+                return Err(format!("Failed to fetch Netlify deploys: {}", status));
+            }
+
+            let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+            let batch: Vec<Deployment> = data
+                .as_array()
+                .map(|deploys| deploys.iter().filter_map(parse_netlify_deploy).collect())
+                .unwrap_or_default();
+
+            let reached_end = batch.len() < 100 || !batch.iter().all(in_window);
+            deployments.extend(batch.into_iter().filter(in_window));
+            if reached_end {
+                break;
+            }
+        }
+
+        Ok(deployments)
+    }
+}
+
+async fn fetch_netlify_metrics(
+    client: &reqwest::Client,
+    netlify: &NetlifyDeployments,
+    window_days: u32,
+) -> Result<NetlifyMetrics, String> {
+    let api_base = "https://api.netlify.com/api/v1";
+
+    let site_response = client
+        .get(format!("{}/sites/{}", api_base, netlify.site_id))
+        .bearer_auth(&netlify.api_key)
         .send()
         .await
-        .map_err(|e| format!("Failed to refresh token: {}", e))?;
+        .map_err(|e| e.to_string())?;
 
-    let status = response.status();
-    let body = response.text().await.map_err(|e| e.to_string())?;
+    let status = site_response.status();
+    if !status.is_success() {
+        let error_text = site_response.text().await.unwrap_or_default();
+        println!("Netlify error: {}", error_text);
+        return Err(format!("Failed to fetch Netlify site: {}", status));
+    }
 
-    if status.is_success() {
-        Ok(body)
-    } else {
-        Err(format!("Token refresh failed: {}", body))
+    let site: serde_json::Value = site_response.json().await.map_err(|e| e.to_string())?;
+
+    let deployments = netlify
+        .fetch_deployments(client, deployment_window_start(window_days))
+        .await?;
+    let current_deploy = parse_netlify_deploy(&site["published_deploy"]);
+
+    let forms: Vec<NetlifyForm> = match client
+        .get(format!("{}/sites/{}/forms", api_base, netlify.site_id))
+        .bearer_auth(&netlify.api_key)
+        .send()
+        .await
+    {
+        Ok(resp) if resp.status().is_success() => resp
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|data| data.as_array().cloned())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|form| {
+                Some(NetlifyForm {
+                    id: form["id"].as_str()?.to_string(),
+                    name: form["name"].as_str().unwrap_or("Untitled form").to_string(),
+                    submission_count: form["submission_count"].as_i64().unwrap_or(0),
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let form_submissions = forms.iter().map(|f| f.submission_count).sum();
+
+    // Bandwidth is tracked per account for the current billing period
+    let mut bandwidth = None;
+    if let Some(account_slug) = site["account_slug"].as_str() {
+        if let Ok(resp) = client
+            .get(format!("{}/accounts/{}/bandwidth", api_base, account_slug))
+            .bearer_auth(&netlify.api_key)
+            .send()
+            .await
+        {
+            if let Ok(data) = resp.json::<serde_json::Value>().await {
+                if let Some(used) = data["used"].as_i64() {
+                    bandwidth = Some(NetlifyBandwidth {
+                        used_bytes: used,
+                        included_bytes: data["included"].as_i64().unwrap_or(0),
+                        period_start: data["period_start_date"].as_str().map(|s| s.to_string()),
+                        period_end: data["period_end_date"].as_str().map(|s| s.to_string()),
+                    });
+                }
+            }
+        }
     }
+
+    let history = summarize_deployments(deployments, window_days);
+
+    println!(
+        "Netlify: {} deploys in {} days, {:.1}% success, {} form submissions",
+        history.total_deployments, window_days, history.success_rate, form_submissions
+    );
+
+    Ok(NetlifyMetrics {
+        history,
+        current_deploy,
+        forms,
+        form_submissions,
+        bandwidth,
+    })
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GoogleCalendarListEntry {
-    id: String,
+// ==========================================
+// Railway API Functions
+// ==========================================
+
+struct RailwayDeployments {
+    api_key: String,
+    project_id: String,
+    service_id: Option<String>,
+}
+
+fn normalize_railway_status(status: &str) -> DeploymentState {
+    match status {
+        // A REMOVED deploy succeeded and was later superseded by a newer one
+        "SUCCESS" | "REMOVED" => DeploymentState::Ready,
+        "FAILED" | "CRASHED" => DeploymentState::Error,
+        "SKIPPED" => DeploymentState::Canceled,
+        "QUEUED" | "WAITING" | "INITIALIZING" => DeploymentState::Queued,
+        "BUILDING" | "DEPLOYING" => DeploymentState::Building,
+        // REMOVING/SLEEPING deploys are being torn down or idled, not failed
+        "REMOVING" | "SLEEPING" => DeploymentState::Inactive,
+        _ => DeploymentState::Unknown,
+    }
+}
+
+async fn railway_query(
+    client: &reqwest::Client,
+    api_key: &str,
+    query: &str,
+    variables: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let response = client
+        .post("https://backboard.railway.app/graphql/v2")
+        .bearer_auth(api_key)
+        .json(&serde_json::json!({ "query": query, "variables": variables }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+
+    if let Some(message) = data["errors"][0]["message"].as_str() {
+        println!("Railway error: {}", message);
+        return Err(format!("Railway query failed: {}", message));
+    }
+    if !status.is_success() {
+        return Err(format!("Failed to fetch Railway metrics: {}", status));
+    }
+
+    Ok(data["data"].clone())
+}
+
+impl DeploymentProvider for RailwayDeployments {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(RailwayDeployments {
+            api_key: integration.api_key.clone()?,
+            project_id: integration.project_id.clone()?,
+            service_id: integration.service_id.clone(),
+        })
+    }
+
+    async fn fetch_deployments(
+        &self,
+        client: &reqwest::Client,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Deployment>, String> {
+        let mut input = serde_json::json!({ "projectId": self.project_id });
+        if let Some(service) = &self.service_id {
+            input["serviceId"] = serde_json::json!(service);
+        }
+
+        let data = railway_query(
+            client,
+            &self.api_key,
+            "query($input: DeploymentListInput!, $first: Int) {
+                deployments(input: $input, first: $first) {
+                    edges { node { id status createdAt updatedAt staticUrl url meta environment { name } service { name } } }
+                }
+            }",
+            serde_json::json!({ "input": input, "first": 100 }),
+        ).await?;
+
+        let empty_vec = vec![];
+        Ok(data["deployments"]["edges"]
+            .as_array()
+            .unwrap_or(&empty_vec)
+            .iter()
+            .map(|edge| &edge["node"])
+            .filter_map(|node| {
+                let created = parse_timestamp(&node["createdAt"]).filter(|dt| *dt >= since)?;
+                let state = normalize_railway_status(node["status"].as_str().unwrap_or("unknown"));
+                // updatedAt is when the deploy reached its final status
+                let finished = parse_timestamp(&node["updatedAt"]).filter(|_| state.is_finished());
+                let meta = &node["meta"];
+                let environment = node["environment"]["name"].as_str().unwrap_or("production");
+
+                Some(Deployment {
+                    id: node["id"].as_str()?.to_string(),
+                    provider: "railway".to_string(),
+                    name: node["service"]["name"].as_str().unwrap_or_default().to_string(),
+                    environment: if environment == "production" { "production" } else { "preview" }.to_string(),
+                    state,
+                    created_at: created.to_rfc3339(),
+                    started_at: Some(created.to_rfc3339()),
+                    finished_at: finished.map(|dt| dt.to_rfc3339()),
+                    duration: duration_between(Some(created), finished),
+                    url: node["staticUrl"]
+                        .as_str()
+                        .or_else(|| node["url"].as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    commit_sha: meta["commitHash"].as_str().map(|s| s.to_string()),
+                    commit_message: meta["commitMessage"].as_str().map(|s| s.to_string()),
+                    commit_author: meta["commitAuthor"].as_str().map(|s| s.to_string()),
+                })
+            })
+            .collect())
+    }
+}
+
+async fn fetch_railway_metrics(
+    client: &reqwest::Client,
+    railway: &RailwayDeployments,
+    window_days: u32,
+) -> Result<HostingMetrics, String> {
+    let deployments = railway
+        .fetch_deployments(client, deployment_window_start(window_days))
+        .await?;
+
+    // Replica counts live on each service instance (one per environment)
+    let (running_instances, total_instances) = match &railway.service_id {
+        Some(service) => {
+            let instances = railway_query(
+                client,
+                &railway.api_key,
+                "query($id: String!) {
+                    service(id: $id) {
+                        serviceInstances { edges { node { numReplicas latestDeployment { status } } } }
+                    }
+                }",
+                serde_json::json!({ "id": service }),
+            ).await.unwrap_or_default();
+
+            let mut running = 0;
+            let mut total = 0;
+            for edge in instances["service"]["serviceInstances"]["edges"].as_array().into_iter().flatten() {
+                let replicas = edge["node"]["numReplicas"].as_i64().unwrap_or(1) as i32;
+                total += replicas;
+                if edge["node"]["latestDeployment"]["status"].as_str() == Some("SUCCESS") {
+                    running += replicas;
+                }
+            }
+            (running, total)
+        }
+        None => (0, 0),
+    };
+
+    let history = summarize_deployments(deployments, window_days);
+    let health = hosting_health(&history, running_instances, total_instances);
+
+    println!("Railway: {} deploys, {}/{} replicas running ({})",
+        history.total_deployments, running_instances, total_instances, health);
+
+    Ok(HostingMetrics {
+        provider: "railway".to_string(),
+        history,
+        running_instances,
+        total_instances,
+        health,
+    })
+}
+
+// ==========================================
+// Render API Functions
+// ==========================================
+
+struct RenderDeployments {
+    api_key: String,
+    service_id: String,
+}
+
+fn normalize_render_status(status: &str) -> DeploymentState {
+    match status {
+        // A deactivated deploy was live until a newer one replaced it
+        "live" | "deactivated" => DeploymentState::Ready,
+        "build_failed" | "update_failed" | "pre_deploy_failed" => DeploymentState::Error,
+        "canceled" => DeploymentState::Canceled,
+        "created" => DeploymentState::Queued,
+        "build_in_progress" | "update_in_progress" | "pre_deploy_in_progress" => DeploymentState::Building,
+        _ => DeploymentState::Unknown,
+    }
+}
+
+impl RenderDeployments {
+    async fn fetch_service(&self, client: &reqwest::Client) -> Result<serde_json::Value, String> {
+        let response = client
+            .get(format!("https://api.render.com/v1/services/{}", self.service_id))
+            .bearer_auth(&self.api_key)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("Render error: {}", error_text);
+            return Err(format!("Failed to fetch Render service: {}", status));
+        }
+
+        response.json().await.map_err(|e| e.to_string())
+    }
+
+    // Deploys for an already-fetched service, which supplies the name and URL
+    async fn fetch_service_deployments(
+        &self,
+        client: &reqwest::Client,
+        service: &serde_json::Value,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Deployment>, String> {
+        let service_name = service["name"].as_str().unwrap_or_default().to_string();
+        let service_url = service["serviceDetails"]["url"].as_str().unwrap_or_default().to_string();
+
+        // Deploys are returned newest first as [{ deploy: {...}, cursor: "..." }]
+        let mut deployments: Vec<Deployment> = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        'pages: for _ in 0..20 {
+            let mut query = vec![("limit", "100".to_string())];
+            if let Some(c) = &cursor {
+                query.push(("cursor", c.clone()));
+            }
+
+            let data: serde_json::Value = client
+                .get(format!("https://api.render.com/v1/services/{}/deploys", self.service_id))
+                .query(&query)
+                .bearer_auth(&self.api_key)
+                .header("Accept", "application/json")
+                .send()
+                .await
+                .map_err(|e| e.to_string())?
+                .json()
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let page = data.as_array().cloned().unwrap_or_default();
+            for item in &page {
+                let deploy = &item["deploy"];
+                let Some(created) = parse_timestamp(&deploy["createdAt"]) else {
+                    continue;
+                };
+                if created < since {
+                    break 'pages;
+                }
+
+                let state = normalize_render_status(deploy["status"].as_str().unwrap_or("unknown"));
+                let finished = parse_timestamp(&deploy["finishedAt"]);
+
+                if let Some(id) = deploy["id"].as_str() {
+                    deployments.push(Deployment {
+                        id: id.to_string(),
+                        provider: "render".to_string(),
+                        name: service_name.clone(),
+                        environment: "production".to_string(), // Render deploys straight to the service
+                        state,
+                        created_at: created.to_rfc3339(),
+                        started_at: Some(created.to_rfc3339()),
+                        finished_at: finished.map(|dt| dt.to_rfc3339()),
+                        duration: duration_between(Some(created), finished),
+                        url: service_url.clone(),
+                        commit_sha: deploy["commit"]["id"].as_str().map(|s| s.to_string()),
+                        commit_message: deploy["commit"]["message"].as_str().map(|s| s.to_string()),
+                        commit_author: None,
+                    });
+                }
+            }
+
+            match page.last().and_then(|item| item["cursor"].as_str()) {
+                Some(next) if page.len() == 100 => cursor = Some(next.to_string()),
+                _ => break,
+            }
+        }
+
+        Ok(deployments)
+    }
+}
+
+impl DeploymentProvider for RenderDeployments {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(RenderDeployments {
+            api_key: integration.api_key.clone()?,
+            service_id: integration.project_id.clone()?,
+        })
+    }
+
+    async fn fetch_deployments(
+        &self,
+        client: &reqwest::Client,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Deployment>, String> {
+        let service = self.fetch_service(client).await?;
+        self.fetch_service_deployments(client, &service, since).await
+    }
+}
+
+async fn fetch_render_metrics(
+    client: &reqwest::Client,
+    render: &RenderDeployments,
+    window_days: u32,
+) -> Result<HostingMetrics, String> {
+    let service = render.fetch_service(client).await?;
+    let deployments = render
+        .fetch_service_deployments(client, &service, deployment_window_start(window_days))
+        .await?;
+
+    let suspended = service["suspended"].as_str() == Some("suspended");
+    let total_instances = service["serviceDetails"]["numInstances"].as_i64().unwrap_or(1) as i32;
+
+    let history = summarize_deployments(deployments, window_days);
+
+    // A failed deploy leaves the previous one serving, so only suspension takes the service down
+    let running_instances = if suspended { 0 } else { total_instances };
+    let health = hosting_health(&history, running_instances, total_instances);
+
+    println!("Render: {} deploys, {} instances ({})", history.total_deployments, total_instances, health);
+
+    Ok(HostingMetrics {
+        provider: "render".to_string(),
+        history,
+        running_instances,
+        total_instances,
+        health,
+    })
+}
+
+// ==========================================
+// Fly.io API Functions
+// ==========================================
+
+struct FlyDeployments {
+    api_key: String,
+    app_name: String,
+}
+
+fn normalize_fly_release_status(status: &str) -> DeploymentState {
+    match status.to_lowercase().as_str() {
+        "complete" | "succeeded" | "successful" => DeploymentState::Ready,
+        "failed" => DeploymentState::Error,
+        "interrupted" | "cancelled" | "canceled" => DeploymentState::Canceled,
+        "pending" => DeploymentState::Queued,
+        "running" | "in_progress" => DeploymentState::Building,
+        _ => DeploymentState::Unknown,
+    }
+}
+
+fn is_idle_fly_machine(state: &str) -> bool {
+    matches!(state, "stopping" | "stopped" | "suspending" | "suspended")
+}
+
+impl DeploymentProvider for FlyDeployments {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(FlyDeployments {
+            api_key: integration.api_key.clone()?,
+            app_name: integration.project_id.clone()?,
+        })
+    }
+
+    // Release history is only exposed through Fly's GraphQL API
+    async fn fetch_deployments(
+        &self,
+        client: &reqwest::Client,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Deployment>, String> {
+        let response = client
+            .post("https://api.fly.io/graphql")
+            .bearer_auth(&self.api_key)
+            .json(&serde_json::json!({
+                "query": "query($name: String!) { app(name: $name) { releases(first: 100) { nodes { id version status description createdAt user { email } } } } }",
+                "variables": { "name": self.app_name }
+            }))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("Failed to fetch Fly.io releases: {}", status));
+        }
+
+        let releases: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+        // GraphQL reports failures (unknown app, bad token) in a 200 response
+        if let Some(error) = releases["errors"].as_array().and_then(|errors| errors.first()) {
+            return Err(format!(
+                "Failed to fetch Fly.io releases: {}",
+                error["message"].as_str().unwrap_or("unknown error")
+            ));
+        }
+        let empty_vec = vec![];
+
+        Ok(releases["data"]["app"]["releases"]["nodes"]
+            .as_array()
+            .unwrap_or(&empty_vec)
+            .iter()
+            .filter_map(|release| {
+                let created = parse_timestamp(&release["createdAt"]).filter(|dt| *dt >= since)?;
+
+                Some(Deployment {
+                    id: release["id"].as_str()?.to_string(),
+                    provider: "flyio".to_string(),
+                    name: format!("{} v{}", self.app_name, release["version"].as_i64().unwrap_or(0)),
+                    environment: "production".to_string(),
+                    state: normalize_fly_release_status(release["status"].as_str().unwrap_or("unknown")),
+                    created_at: created.to_rfc3339(),
+                    started_at: Some(created.to_rfc3339()),
+                    finished_at: None, // Releases don't record a completion time
+                    duration: None,
+                    url: format!("https://{}.fly.dev", self.app_name),
+                    commit_sha: None,
+                    commit_message: release["description"].as_str().map(|s| s.to_string()),
+                    commit_author: release["user"]["email"].as_str().map(|s| s.to_string()),
+                })
+            })
+            .collect())
+    }
+}
+
+async fn fetch_flyio_metrics(
+    client: &reqwest::Client,
+    fly: &FlyDeployments,
+    window_days: u32,
+) -> Result<HostingMetrics, String> {
+    // Machines API: one entry per VM, with health check results
+    let machines_response = client
+        .get(format!("https://api.machines.dev/v1/apps/{}/machines", fly.app_name))
+        .bearer_auth(&fly.api_key)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = machines_response.status();
+    if !status.is_success() {
+        let error_text = machines_response.text().await.unwrap_or_default();
+        println!("Fly.io error: {}", error_text);
+        return Err(format!("Failed to fetch Fly.io machines: {}", status));
+    }
+
+    let machines: serde_json::Value = machines_response.json().await.map_err(|e| e.to_string())?;
+    let machines = machines.as_array().cloned().unwrap_or_default();
+
+    let live_machines: Vec<&serde_json::Value> = machines
+        .iter()
+        .filter(|m| !matches!(m["state"].as_str(), Some("destroyed") | Some("destroying")))
+        .collect();
+    // Stopped and suspended machines are idled by auto-stop or scaled down on purpose,
+    // so they don't count toward the instances that should be running
+    let active_machines: Vec<&serde_json::Value> = live_machines
+        .iter()
+        .copied()
+        .filter(|m| !is_idle_fly_machine(m["state"].as_str().unwrap_or_default()))
+        .collect();
+    let stopped_instances = live_machines.len() - active_machines.len();
+    let total_instances = active_machines.len() as i32;
+    let running_instances = active_machines
+        .iter()
+        .filter(|m| m["state"].as_str() == Some("started"))
+        .count() as i32;
+    let failing_checks = active_machines
+        .iter()
+        .flat_map(|m| m["checks"].as_array().cloned().unwrap_or_default())
+        .filter(|check| check["status"].as_str() == Some("critical"))
+        .count();
+
+    // Machine health is still worth reporting when release history can't be read
+    let deployments = match fly
+        .fetch_deployments(client, deployment_window_start(window_days))
+        .await
+    {
+        Ok(deployments) => deployments,
+        Err(e) => {
+            println!("Fly.io error: {}", e);
+            Vec::new()
+        }
+    };
+
+    let history = summarize_deployments(deployments, window_days);
+    // Down only when the app has no machines left or the ones meant to run have failed
+    let health = if live_machines.is_empty() {
+        "down".to_string()
+    } else if failing_checks > 0 && running_instances > 0 {
+        "degraded".to_string()
+    } else {
+        hosting_health(&history, running_instances, total_instances)
+    };
+
+    println!("Fly.io: {} releases, {}/{} machines started, {} stopped ({})",
+        history.total_deployments, running_instances, total_instances, stopped_instances, health);
+
+    Ok(HostingMetrics {
+        provider: "flyio".to_string(),
+        history,
+        running_instances,
+        total_instances,
+        health,
+    })
+}
+
+// ==========================================
+// PostHog API Functions
+// ==========================================
+
+async fn fetch_posthog_metrics(
+    client: &reqwest::Client,
+    api_key: &str,
+    project_id: &str,
+) -> Result<PostHogMetrics, String> {
+    // PostHog uses a different API structure - this is a simplified version
+    let response = client
+        .get(format!(
+            "https://app.posthog.com/api/projects/{}/insights/trend/",
+            project_id
+        ))
+        .header("Authorization", format!("Bearer {}", api_key))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        // For now, return placeholder data - real implementation would parse the response
+        Ok(PostHogMetrics {
+            total_events_24h: 0,
+            unique_users_24h: 0,
+            total_events_7d: 0,
+            unique_users_7d: 0,
+        })
+    } else {
+        Err("Failed to fetch PostHog metrics".to_string())
+    }
+}
+
+// ==========================================
+// Supabase API Functions
+// ==========================================
+
+fn format_bytes(bytes: i64) -> String {
+    let units = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, units[unit])
+    }
+}
+
+// Runs SQL through the Management API, which needs a personal access token rather than a project key
+async fn supabase_management_query(
+    client: &reqwest::Client,
+    access_token: &str,
+    project_id: &str,
+    query: &str,
+) -> Result<Vec<serde_json::Value>, String> {
+    let response = client
+        .post(format!("https://api.supabase.com/v1/projects/{}/database/query", project_id))
+        .bearer_auth(access_token)
+        .json(&serde_json::json!({ "query": query }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        println!("Supabase Management API error: {}", error_text);
+        return Err(format!("Supabase query failed: {}", status));
+    }
+
+    let rows: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    Ok(rows.as_array().cloned().unwrap_or_default())
+}
+
+async fn fetch_supabase_usage(
+    client: &reqwest::Client,
+    access_token: &str,
+    project_id: &str,
+) -> SupabaseUsage {
+    let api_base = format!("https://api.supabase.com/v1/projects/{}", project_id);
+    let mut usage = SupabaseUsage::default();
+
+    // Sizes come straight from Postgres; bigint columns may be returned as strings
+    if let Ok(rows) = supabase_management_query(
+        client,
+        access_token,
+        project_id,
+        "select pg_database_size(current_database()) as database_bytes,
+            (select coalesce(sum((metadata->>'size')::bigint), 0) from storage.objects) as storage_bytes",
+    ).await {
+        if let Some(row) = rows.first() {
+            usage.database_size_bytes = Some(json_number(&row["database_bytes"]) as i64);
+            usage.storage_size_bytes = Some(json_number(&row["storage_bytes"]) as i64);
+        }
+    }
+
+    // Hourly request counts per service for the last day
+    match get_json(
+        client
+            .get(format!("{}/analytics/endpoints/usage.api-counts", api_base))
+            .query(&[("interval", "1day")])
+            .bearer_auth(access_token),
+        "Supabase",
+    ).await {
+        Ok(data) => {
+            if let Some(buckets) = data["result"].as_array() {
+                let total = |key: &str| buckets.iter().map(|b| json_number(&b[key]) as i64).sum();
+                usage.api_requests = Some(SupabaseApiRequests {
+                    rest: total("total_rest_requests"),
+                    auth: total("total_auth_requests"),
+                    storage: total("total_storage_requests"),
+                    realtime: total("total_realtime_requests"),
+                });
+            }
+        }
+        Err(e) => println!("Supabase API counts unavailable: {}", e),
+    }
+
+    // Egress is the response body size summed over the edge logs; each log's
+    // response carries a single headers struct
+    let egress_sql = "select sum(cast(r.headers.content_length as int64)) as egress_bytes \
+        from edge_logs cross join unnest(metadata) as m \
+        cross join unnest(m.response) as r";
+    let end = chrono::Utc::now();
+    let start = end - chrono::Duration::hours(24);
+    match get_json(
+        client
+            .get(format!("{}/analytics/endpoints/logs.all", api_base))
+            .query(&[
+                ("sql", egress_sql.to_string()),
+                ("iso_timestamp_start", start.to_rfc3339()),
+                ("iso_timestamp_end", end.to_rfc3339()),
+            ])
+            .bearer_auth(access_token),
+        "Supabase",
+    ).await {
+        // Query errors come back as { error: ... } with a 200
+        Ok(data) if !data["error"].is_null() => {
+            println!("Supabase egress query failed: {}", data["error"]);
+        }
+        Ok(data) => {
+            if let Some(row) = data["result"].as_array().and_then(|rows| rows.first()) {
+                usage.egress_bytes_24h = Some(json_number(&row["egress_bytes"]) as i64);
+            }
+        }
+        Err(e) => println!("Supabase egress unavailable: {}", e),
+    }
+
+    usage
+}
+
+async fn fetch_supabase_metrics(
+    client: &reqwest::Client,
+    api_key: &str,  // This should be the service_role key
+    project_id: &str,  // This is the project ref (e.g., "xyzcompany")
+    access_token: Option<&str>,  // Optional personal access token for the Management API
+) -> Result<(SupabaseMetrics, UserGrowthMetrics), String> {
+    // Use Supabase Auth Admin API to list users
+    // The project_id should be the project reference (e.g., "abcdefghijklmnop")
+    // API key should be the service_role key
+    let auth_url = format!(
+        "https://{}.supabase.co/auth/v1/admin/users",
+        project_id
+    );
+
+    println!("Fetching Supabase users from: {}", auth_url);
+
+    // The admin endpoint caps per_page at 1000, so page until we get a short page
+    let per_page = 1000;
+    let mut records: Vec<AuthUserRecord> = Vec::new();
+    for page in 1..=200 {
+        let response = client
+            .get(&auth_url)
+            .header("apikey", api_key)
+            .header("Authorization", format!("Bearer {}", api_key))
+            .query(&[("page", page.to_string()), ("per_page", per_page.to_string())])
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("Supabase error: {}", error_text);
+            return Err(format!("Failed to fetch Supabase metrics: {}", status));
+        }
+
+        // The response has { users: [...], aud: "...", ... }
+        let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+        let batch = data["users"].as_array().cloned().unwrap_or_default();
+        records.extend(batch.iter().map(|user| AuthUserRecord {
+            created_at: parse_timestamp(&user["created_at"]),
+            last_active_at: parse_timestamp(&user["last_sign_in_at"]),
+            // confirmed_at is set once either the email or the phone number has been verified
+            verified: ["confirmed_at", "email_confirmed_at", "phone_confirmed_at"]
+                .iter()
+                .any(|key| user[*key].is_string()),
+            // app_metadata.provider is the provider the user first signed up with
+            provider: normalize_auth_provider(user["app_metadata"]["provider"].as_str().unwrap_or("unknown")),
+        }));
+
+        if batch.len() < per_page {
+            break;
+        }
+    }
+
+    let users = user_growth_from_records("supabase", records.len() as i64, &records);
+
+    println!(
+        "Found {} total users ({:?} confirmed), {} new in last 7 days, {:?} active in last 7 days",
+        users.total_users, users.verified_users, users.new_users_7d, users.active_users_7d
+    );
+
+    let usage = match access_token {
+        Some(token) => fetch_supabase_usage(client, token, project_id).await,
+        None => SupabaseUsage::default(),
+    };
+    let api_requests_24h = usage
+        .api_requests
+        .as_ref()
+        .map(|r| r.rest + r.auth + r.storage + r.realtime)
+        .unwrap_or(0);
+
+    let supabase = SupabaseMetrics {
+        total_users: users.total_users as i32,
+        new_users_7d: users.new_users_7d as i32,
+        database_size: usage
+            .database_size_bytes
+            .map(format_bytes)
+            .unwrap_or_else(|| "N/A".to_string()),
+        api_requests_24h,
+        database_size_bytes: usage.database_size_bytes,
+        storage_size_bytes: usage.storage_size_bytes,
+        egress_bytes_24h: usage.egress_bytes_24h,
+        api_requests_by_service: usage.api_requests,
+    };
+
+    Ok((supabase, users))
+}
+
+// ==========================================
+// Firebase API Functions
+// ==========================================
+
+#[derive(Serialize)]
+struct ServiceAccountClaims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: i64,
+    exp: i64,
+}
+
+// Exchanges a service-account key for an access token; the JWT is signed locally
+async fn fetch_service_account_token(
+    client: &reqwest::Client,
+    service_account: &serde_json::Value,
+    scope: &str,
+) -> Result<String, String> {
+    let client_email = service_account["client_email"]
+        .as_str()
+        .ok_or("Service account JSON is missing client_email")?;
+    let private_key = service_account["private_key"]
+        .as_str()
+        .ok_or("Service account JSON is missing private_key")?;
+    let token_uri = service_account["token_uri"]
+        .as_str()
+        .unwrap_or("https://oauth2.googleapis.com/token");
+
+    let now = chrono::Utc::now().timestamp();
+    let claims = ServiceAccountClaims {
+        iss: client_email,
+        scope,
+        aud: token_uri,
+        iat: now,
+        exp: now + 3600,
+    };
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(private_key.as_bytes())
+        .map_err(|e| format!("Invalid service account private key: {}", e))?;
+    let assertion = jsonwebtoken::encode(
+        &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256),
+        &claims,
+        &key,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let response = client
+        .post(token_uri)
+        .form(&[
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", assertion.as_str()),
+        ])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    match data["access_token"].as_str() {
+        Some(token) => Ok(token.to_string()),
+        None => {
+            println!("Service account token error: {}", data);
+            Err(format!("Failed to get service account token: {}", status))
+        }
+    }
+}
+
+async fn fetch_firestore_count(
+    client: &reqwest::Client,
+    access_token: &str,
+    project_id: &str,
+    collection: &str,
+) -> Result<i64, String> {
+    let response = client
+        .post(format!(
+            "https://firestore.googleapis.com/v1/projects/{}/databases/(default)/documents:runAggregationQuery",
+            project_id
+        ))
+        .bearer_auth(access_token)
+        .json(&serde_json::json!({
+            "structuredAggregationQuery": {
+                "structuredQuery": { "from": [{ "collectionId": collection }] },
+                "aggregations": [{ "alias": "count", "count": {} }]
+            }
+        }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("Failed to count Firestore collection {}: {}", collection, status));
+    }
+
+    // Streams back [{ result: { aggregateFields: { count: { integerValue: "42" } } }, readTime }]
+    let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    Ok(json_number(&data[0]["result"]["aggregateFields"]["count"]["integerValue"]) as i64)
+}
+
+async fn fetch_firebase_metrics(
+    client: &reqwest::Client,
+    service_account_json: &str,
+    project_id: Option<&str>,
+    collections: &[String],
+) -> Result<(FirebaseMetrics, UserGrowthMetrics), String> {
+    let service_account: serde_json::Value = serde_json::from_str(service_account_json)
+        .map_err(|e| format!("Invalid service account JSON: {}", e))?;
+    let project_id = project_id
+        .or_else(|| service_account["project_id"].as_str())
+        .ok_or("No Firebase project ID configured")?
+        .to_string();
+
+    let access_token = fetch_service_account_token(
+        client,
+        &service_account,
+        "https://www.googleapis.com/auth/identitytoolkit https://www.googleapis.com/auth/datastore",
+    ).await?;
+
+    // Identity Toolkit pages users 1000 at a time
+    let mut records: Vec<AuthUserRecord> = Vec::new();
+    let mut page_token: Option<String> = None;
+    for _ in 0..200 {
+        let mut query = vec![("maxResults", "1000".to_string())];
+        if let Some(token) = &page_token {
+            query.push(("nextPageToken", token.clone()));
+        }
+
+        let response = client
+            .get(format!(
+                "https://identitytoolkit.googleapis.com/v1/projects/{}/accounts:batchGet",
+                project_id
+            ))
+            .query(&query)
+            .bearer_auth(&access_token)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("Firebase error: {}", error_text);
+            return Err(format!("Failed to fetch Firebase users: {}", status));
+        }
+
+        let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+        for user in data["users"].as_array().into_iter().flatten() {
+            // providerUserInfo lists every linked provider ("password", "google.com", ...); count the first
+            let provider = user["providerUserInfo"][0]["providerId"]
+                .as_str()
+                .unwrap_or("unknown")
+                .trim_end_matches(".com");
+
+            // Timestamps are millisecond strings
+            let millis = |value: &serde_json::Value| {
+                Some(json_number(value) as i64)
+                    .filter(|ms| *ms > 0)
+                    .and_then(chrono::DateTime::from_timestamp_millis)
+            };
+
+            records.push(AuthUserRecord {
+                created_at: millis(&user["createdAt"]),
+                last_active_at: millis(&user["lastLoginAt"]),
+                verified: user["emailVerified"].as_bool().unwrap_or(false),
+                provider: normalize_auth_provider(provider),
+            });
+        }
+
+        match data["nextPageToken"].as_str() {
+            Some(token) if !token.is_empty() => page_token = Some(token.to_string()),
+            _ => break,
+        }
+    }
+
+    let users = user_growth_from_records("firebase", records.len() as i64, &records);
+
+    let mut collection_counts = Vec::new();
+    // Blank entries come from trailing commas in the Collections field
+    for collection in collections.iter().filter(|c| !c.trim().is_empty()) {
+        match fetch_firestore_count(client, &access_token, &project_id, collection).await {
+            Ok(count) => collection_counts.push(FirestoreCollectionCount {
+                collection: collection.clone(),
+                count,
+            }),
+            Err(e) => println!("Firestore error: {}", e),
+        }
+    }
+
+    println!(
+        "Firebase: {} total users, {} new in last 7 days, {} collections counted",
+        users.total_users, users.new_users_7d, collection_counts.len()
+    );
+
+    let firebase = FirebaseMetrics {
+        total_users: users.total_users as i32,
+        new_users_7d: users.new_users_7d as i32,
+        collections: collection_counts,
+    };
+
+    Ok((firebase, users))
+}
+
+// ==========================================
+// User Growth Functions
+// ==========================================
+
+const USER_GROWTH_DAYS: i64 = 30;
+
+// One user as seen by an auth provider, enough to derive growth and activity
+struct AuthUserRecord {
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
+    last_active_at: Option<chrono::DateTime<chrono::Utc>>,
+    verified: bool,
+    provider: &'static str, // Already normalised with normalize_auth_provider
+}
+
+// Collapse auth provider names onto the frontend's AuthProvider set
+fn normalize_auth_provider(provider: &str) -> &'static str {
+    match provider {
+        "email" | "phone" | "password" => "email",
+        "google" => "google",
+        "github" => "github",
+        "apple" => "apple",
+        "twitter" => "twitter",
+        "discord" => "discord",
+        _ => "unknown",
+    }
+}
+
+fn auth_provider_breakdown(counts: HashMap<&str, i32>) -> Vec<AuthProviderCount> {
+    let mut providers: Vec<AuthProviderCount> = counts
+        .into_iter()
+        .map(|(provider, count)| AuthProviderCount {
+            provider: provider.to_string(),
+            count,
+        })
+        .collect();
+    providers.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.provider.cmp(&b.provider)));
+    providers
+}
+
+// Fills every day in the window, including days with no signups
+fn daily_signup_series(counts_by_date: &HashMap<String, i64>) -> Vec<DailyCount> {
+    let today = chrono::Utc::now();
+    (0..USER_GROWTH_DAYS)
+        .rev()
+        .map(|days_ago| {
+            let date = (today - chrono::Duration::days(days_ago)).format("%Y-%m-%d").to_string();
+            let count = counts_by_date.get(&date).copied().unwrap_or(0);
+            DailyCount { date, count }
+        })
+        .collect()
+}
+
+fn user_growth_from_records(
+    provider: &str,
+    total_users: i64,
+    records: &[AuthUserRecord],
+) -> UserGrowthMetrics {
+    let now = chrono::Utc::now();
+    let count_within = |time: fn(&AuthUserRecord) -> Option<chrono::DateTime<chrono::Utc>>, days: i64| {
+        records
+            .iter()
+            .filter(|r| time(r).is_some_and(|dt| dt > now - chrono::Duration::days(days)))
+            .count() as i64
+    };
+    let created = |r: &AuthUserRecord| r.created_at;
+    let active = |r: &AuthUserRecord| r.last_active_at;
+
+    let mut signups_by_date: HashMap<String, i64> = HashMap::new();
+    let mut provider_counts: HashMap<&str, i32> = HashMap::new();
+    for record in records {
+        if let Some(created_at) = record.created_at {
+            *signups_by_date.entry(created_at.format("%Y-%m-%d").to_string()).or_insert(0) += 1;
+        }
+        *provider_counts.entry(record.provider).or_insert(0) += 1;
+    }
+
+    UserGrowthMetrics {
+        provider: provider.to_string(),
+        total_users,
+        new_users_1d: count_within(created, 1),
+        new_users_7d: count_within(created, 7),
+        new_users_30d: count_within(created, 30),
+        daily_signups: daily_signup_series(&signups_by_date),
+        auth_providers: auth_provider_breakdown(provider_counts),
+        verified_users: Some(records.iter().filter(|r| r.verified).count() as i64),
+        active_users_1d: Some(count_within(active, 1)),
+        active_users_7d: Some(count_within(active, 7)),
+        active_users_30d: Some(count_within(active, 30)),
+    }
+}
+
+// ==========================================
+// Clerk API Functions
+// ==========================================
+
+async fn fetch_clerk_user_growth(
+    client: &reqwest::Client,
+    secret_key: &str,
+) -> Result<UserGrowthMetrics, String> {
+    let api_base = "https://api.clerk.com/v1";
+
+    let count_response = client
+        .get(format!("{}/users/count", api_base))
+        .bearer_auth(secret_key)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = count_response.status();
+    if !status.is_success() {
+        let error_text = count_response.text().await.unwrap_or_default();
+        println!("Clerk error: {}", error_text);
+        return Err(format!("Failed to fetch Clerk users: {}", status));
+    }
+
+    let count: serde_json::Value = count_response.json().await.map_err(|e| e.to_string())?;
+    let total_users = count["total_count"].as_i64().unwrap_or(0);
+
+    // Activity needs every user, so page through the whole list (500 is Clerk's max page size)
+    let mut records: Vec<AuthUserRecord> = Vec::new();
+    for page in 0..200 {
+        let users: serde_json::Value = client
+            .get(format!("{}/users", api_base))
+            .query(&[
+                ("limit", "500".to_string()),
+                ("offset", (page * 500).to_string()),
+                ("order_by", "-created_at".to_string()),
+            ])
+            .bearer_auth(secret_key)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let batch = users.as_array().cloned().unwrap_or_default();
+        records.extend(batch.iter().map(|user| {
+            // Timestamps are milliseconds; external accounts are "oauth_google", "oauth_github", ...
+            let provider = user["external_accounts"][0]["provider"]
+                .as_str()
+                .map(|p| p.trim_start_matches("oauth_"))
+                .unwrap_or("email");
+
+            AuthUserRecord {
+                created_at: parse_timestamp(&user["created_at"]),
+                last_active_at: parse_timestamp(&user["last_active_at"])
+                    .or_else(|| parse_timestamp(&user["last_sign_in_at"])),
+                verified: user["email_addresses"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .chain(user["phone_numbers"].as_array().into_iter().flatten())
+                    .any(|address| address["verification"]["status"].as_str() == Some("verified")),
+                provider: normalize_auth_provider(provider),
+            }
+        }));
+
+        if batch.len() < 500 {
+            break;
+        }
+    }
+
+    let growth = user_growth_from_records("clerk", total_users, &records);
+
+    println!(
+        "Clerk: {} total users, {} new in last 7 days, {:?} MAU",
+        growth.total_users, growth.new_users_7d, growth.active_users_30d
+    );
+
+    Ok(growth)
+}
+
+// ==========================================
+// Auth0 API Functions
+// ==========================================
+
+// Auth0 connection strategies ("auth0" is the username/password database)
+fn normalize_auth0_strategy(strategy: &str) -> &'static str {
+    match strategy {
+        "auth0" | "email" | "sms" | "passwordless" => "email",
+        other => normalize_auth_provider(other.trim_end_matches("-oauth2")),
+    }
+}
+
+async fn fetch_auth0_user_growth(
+    client: &reqwest::Client,
+    domain: &str,
+    api_key: &str,              // Management API token, or client ID when a secret is set
+    client_secret: Option<&str>,
+) -> Result<UserGrowthMetrics, String> {
+    let domain = domain
+        .trim_start_matches("https://")
+        .trim_end_matches('/');
+    let api_base = format!("https://{}/api/v2", domain);
+
+    // Dashboard tokens expire after a day, so prefer a machine-to-machine app when configured
+    let token = match client_secret {
+        Some(secret) => {
+            let data: serde_json::Value = client
+                .post(format!("https://{}/oauth/token", domain))
+                .json(&serde_json::json!({
+                    "grant_type": "client_credentials",
+                    "client_id": api_key,
+                    "client_secret": secret,
+                    "audience": format!("{}/", api_base),
+                }))
+                .send()
+                .await
+                .map_err(|e| e.to_string())?
+                .json()
+                .await
+                .map_err(|e| e.to_string())?;
+
+            data["access_token"]
+                .as_str()
+                .map(|s| s.to_string())
+                .ok_or("Auth0 did not return an access token")?
+        }
+        None => api_key.to_string(),
+    };
+
+    // per_page=0 with include_totals returns just the count
+    let totals_response = client
+        .get(format!("{}/users", api_base))
+        .query(&[("per_page", "0"), ("include_totals", "true"), ("search_engine", "v3")])
+        .bearer_auth(&token)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = totals_response.status();
+    if !status.is_success() {
+        let error_text = totals_response.text().await.unwrap_or_default();
+        println!("Auth0 error: {}", error_text);
+        return Err(format!("Failed to fetch Auth0 users: {}", status));
+    }
+
+    let totals: serde_json::Value = totals_response.json().await.map_err(|e| e.to_string())?;
+    let total_users = totals["total"].as_i64().unwrap_or(0);
+
+    // Daily stats give signups per day without listing users (which caps at 1000)
+    let today = chrono::Utc::now();
+    let from = today - chrono::Duration::days(USER_GROWTH_DAYS - 1);
+    let daily_stats: serde_json::Value = client
+        .get(format!("{}/stats/daily", api_base))
+        .query(&[
+            ("from", from.format("%Y%m%d").to_string()),
+            ("to", today.format("%Y%m%d").to_string()),
+        ])
+        .bearer_auth(&token)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .unwrap_or_default();
+
+    let mut signups_by_date: HashMap<String, i64> = HashMap::new();
+    for day in daily_stats.as_array().into_iter().flatten() {
+        if let Some(date) = day["date"].as_str() {
+            signups_by_date.insert(normalize_analytics_date(date), day["signups"].as_i64().unwrap_or(0));
+        }
+    }
+    let daily_signups = daily_signup_series(&signups_by_date);
+    let sum_last = |days: usize| daily_signups.iter().rev().take(days).map(|d| d.count).sum::<i64>();
+
+    // Monthly active users (last 30 days)
+    let active_users_30d = match client
+        .get(format!("{}/stats/active-users", api_base))
+        .bearer_auth(&token)
+        .send()
+        .await
+    {
+        Ok(resp) if resp.status().is_success() => resp.json::<serde_json::Value>().await.ok().and_then(|v| v.as_i64()),
+        _ => None,
+    };
+
+    // Count users per connection, then fold connections into providers
+    let mut provider_counts: HashMap<&str, i32> = HashMap::new();
+    if let Ok(resp) = client
+        .get(format!("{}/connections", api_base))
+        .query(&[("fields", "name,strategy")])
+        .bearer_auth(&token)
+        .send()
+        .await
+    {
+        let connections: serde_json::Value = resp.json().await.unwrap_or_default();
+        for connection in connections.as_array().into_iter().flatten() {
+            let (Some(name), Some(strategy)) = (connection["name"].as_str(), connection["strategy"].as_str()) else {
+                continue;
+            };
+
+            let count: serde_json::Value = match client
+                .get(format!("{}/users", api_base))
+                .query(&[
+                    ("per_page", "0".to_string()),
+                    ("include_totals", "true".to_string()),
+                    ("search_engine", "v3".to_string()),
+                    ("q", format!("identities.connection:\"{}\"", name)),
+                ])
+                .bearer_auth(&token)
+                .send()
+                .await
+            {
+                Ok(resp) => resp.json().await.unwrap_or_default(),
+                Err(_) => continue,
+            };
+
+            let users = count["total"].as_i64().unwrap_or(0) as i32;
+            if users > 0 {
+                *provider_counts.entry(normalize_auth0_strategy(strategy)).or_insert(0) += users;
+            }
+        }
+    }
+
+    let verified_users = match client
+        .get(format!("{}/users", api_base))
+        .query(&[
+            ("per_page", "0"),
+            ("include_totals", "true"),
+            ("search_engine", "v3"),
+            ("q", "email_verified:true"),
+        ])
+        .bearer_auth(&token)
+        .send()
+        .await
+    {
+        Ok(resp) if resp.status().is_success() => resp
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|v| v["total"].as_i64()),
+        _ => None,
+    };
+
+    let growth = UserGrowthMetrics {
+        provider: "auth0".to_string(),
+        total_users,
+        new_users_1d: sum_last(1),
+        new_users_7d: sum_last(7),
+        new_users_30d: sum_last(30),
+        daily_signups,
+        auth_providers: auth_provider_breakdown(provider_counts),
+        verified_users,
+        active_users_1d: None,
+        active_users_7d: None,
+        active_users_30d,
+    };
+
+    println!(
+        "Auth0: {} total users, {} new in last 7 days, {:?} MAU",
+        growth.total_users, growth.new_users_7d, growth.active_users_30d
+    );
+
+    Ok(growth)
+}
+
+// ==========================================
+// Database Platform Functions
+// ==========================================
+
+// Implemented by every managed database host so the dashboard can show one health card.
+trait DatabaseProvider: Sized {
+    fn from_integration(integration: &Integration) -> Option<Self>;
+
+    async fn fetch_database_health(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<DatabaseHealthMetrics, String>;
+}
+
+async fn fetch_database_health_from<P: DatabaseProvider>(
+    client: &reqwest::Client,
+    integration: &Integration,
+) -> Option<DatabaseHealthMetrics> {
+    let provider = P::from_integration(integration)?;
+    match provider.fetch_database_health(client).await {
+        Ok(metrics) => Some(metrics),
+        Err(e) => {
+            println!("{} database error: {}", integration.integration_type, e);
+            None
+        }
+    }
+}
+
+async fn fetch_database_health(
+    client: &reqwest::Client,
+    integration: &Integration,
+) -> Option<DatabaseHealthMetrics> {
+    match integration.integration_type.as_str() {
+        "planetscale" => fetch_database_health_from::<PlanetScaleDatabase>(client, integration).await,
+        "neon" => fetch_database_health_from::<NeonDatabase>(client, integration).await,
+        "mongodb" => fetch_database_health_from::<AtlasDatabase>(client, integration).await,
+        _ => None,
+    }
+}
+
+// Sleeping/paused instances are idle by choice, so they don't count against health
+fn database_health(instances: &[DatabaseInstance]) -> String {
+    let active: Vec<&DatabaseInstance> = instances
+        .iter()
+        .filter(|i| !matches!(i.status.as_str(), "sleeping" | "paused"))
+        .collect();
+    let ready = active.iter().filter(|i| i.status == "ready").count();
+    let primary_down = active.iter().any(|i| i.primary && i.status == "error");
+
+    let health = if instances.is_empty() {
+        "unknown"
+    } else if primary_down || (!active.is_empty() && ready == 0) {
+        "down"
+    } else if ready < active.len() {
+        "degraded"
+    } else {
+        "healthy"
+    };
+    health.to_string()
+}
+
+async fn get_json(
+    request: reqwest::RequestBuilder,
+    provider: &str,
+) -> Result<serde_json::Value, String> {
+    let response = request.send().await.map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        println!("{} error: {}", provider, error_text);
+        return Err(format!("Failed to fetch {} metrics: {}", provider, status));
+    }
+
+    response.json().await.map_err(|e| e.to_string())
+}
+
+// ==========================================
+// PlanetScale Database
+// ==========================================
+
+struct PlanetScaleDatabase {
+    token_id: String,
+    token: String,
+    organization: String,
+    database: String,
+}
+
+fn normalize_planetscale_state(state: &str) -> &'static str {
+    match state {
+        "ready" => "ready",
+        "sleeping" | "hibernated" => "sleeping",
+        "pending" | "importing" | "awakening" | "sleep_in_progress" => "provisioning",
+        _ => "unknown",
+    }
+}
+
+// Sums every sample of one metric in Prometheus text format ("name{labels} value [timestamp]")
+fn prometheus_sum(body: &str, metric: &str) -> Option<f64> {
+    let values: Vec<f64> = body
+        .lines()
+        .filter_map(|line| {
+            let rest = line.strip_prefix(metric)?;
+            let rest = match rest.strip_prefix('{') {
+                Some(labelled) => labelled.split_once('}')?.1,
+                None if rest.starts_with(' ') => rest,
+                None => return None, // A longer metric name sharing the prefix
+            };
+            rest.split_whitespace().next()?.parse::<f64>().ok()
+        })
+        .collect();
+    (!values.is_empty()).then(|| values.iter().sum())
+}
+
+impl PlanetScaleDatabase {
+    // Table storage per branch, scraped from the Prometheus metrics endpoint (needs the
+    // read_metrics_endpoints permission). Discovery lists one target per branch.
+    async fn branch_storage(&self, client: &reqwest::Client, auth: &str) -> HashMap<String, i64> {
+        let mut storage = HashMap::new();
+        let targets = match get_json(
+            client
+                .get(format!("https://api.planetscale.com/v1/organizations/{}/metrics", self.organization))
+                .header("Authorization", auth),
+            "PlanetScale",
+        ).await {
+            Ok(targets) => targets,
+            Err(e) => {
+                println!("PlanetScale storage unavailable: {}", e);
+                return storage;
+            }
+        };
+
+        for target in targets.as_array().into_iter().flatten() {
+            let labels = &target["labels"];
+            if labels["planetscale_database"].as_str() != Some(self.database.as_str()) {
+                continue;
+            }
+            let (Some(branch), Some(host), Some(path)) = (
+                labels["planetscale_branch"].as_str(),
+                target["targets"][0].as_str(),
+                labels["__metrics_path__"].as_str(),
+            ) else {
+                continue;
+            };
+
+            let scheme = labels["__scheme__"].as_str().unwrap_or("https");
+            let body = match client
+                .get(format!("{}://{}{}", scheme, host, path))
+                .header("Authorization", auth)
+                .send()
+                .await
+            {
+                Ok(resp) if resp.status().is_success() => resp.text().await.unwrap_or_default(),
+                _ => continue,
+            };
+            if let Some(bytes) = prometheus_sum(&body, "planetscale_tables_storage_bytes") {
+                storage.insert(branch.to_string(), bytes as i64);
+            }
+        }
+
+        storage
+    }
+}
+
+impl DatabaseProvider for PlanetScaleDatabase {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(PlanetScaleDatabase {
+            token_id: integration.api_key.clone()?,
+            token: integration.api_secret.clone()?,
+            organization: integration.team_id.clone()?,
+            database: integration.project_id.clone()?,
+        })
+    }
+
+    async fn fetch_database_health(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<DatabaseHealthMetrics, String> {
+        let api_base = format!(
+            "https://api.planetscale.com/v1/organizations/{}/databases/{}",
+            self.organization, self.database
+        );
+        // Service tokens authenticate as "<id>:<token>" with no scheme
+        let auth = format!("{}:{}", self.token_id, self.token);
+
+        let database = get_json(client.get(&api_base).header("Authorization", &auth), "PlanetScale").await?;
+        let branches = get_json(
+            client
+                .get(format!("{}/branches", api_base))
+                .query(&[("per_page", "100")])
+                .header("Authorization", &auth),
+            "PlanetScale",
+        ).await?;
+
+        let database_state = normalize_planetscale_state(database["state"].as_str().unwrap_or("unknown"));
+        let storage = self.branch_storage(client, &auth).await;
+        let empty_vec = vec![];
+        let instances: Vec<DatabaseInstance> = branches["data"]
+            .as_array()
+            .unwrap_or(&empty_vec)
+            .iter()
+            .filter_map(|branch| {
+                // A sleeping database takes every branch down with it
+                let status = if database_state != "ready" {
+                    database_state
+                } else if branch["ready"].as_bool().unwrap_or(false) {
+                    "ready"
+                } else {
+                    "provisioning"
+                };
+
+                let name = branch["name"].as_str()?;
+                Some(DatabaseInstance {
+                    name: name.to_string(),
+                    status: status.to_string(),
+                    region: branch["region"]["slug"].as_str().map(|s| s.to_string()),
+                    primary: branch["production"].as_bool().unwrap_or(false),
+                    storage_bytes: storage.get(name).copied(),
+                })
+            })
+            .collect();
+
+        let storage_used_bytes = (!storage.is_empty()).then(|| storage.values().sum());
+        let health = database_health(&instances);
+        println!("PlanetScale: {} branches, {:?} bytes stored ({})", instances.len(), storage_used_bytes, health);
+
+        // PlanetScale bills a provisioned cluster size rather than metered compute hours
+        Ok(DatabaseHealthMetrics {
+            provider: "planetscale".to_string(),
+            health,
+            instances,
+            storage_used_bytes,
+            storage_limit_bytes: None,
+            compute_hours: None,
+            connections: None,
+        })
+    }
+}
+
+// ==========================================
+// Neon Database
+// ==========================================
+
+struct NeonDatabase {
+    api_key: String,
+    project_id: String,
+}
+
+impl DatabaseProvider for NeonDatabase {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(NeonDatabase {
+            api_key: integration.api_key.clone()?,
+            project_id: integration.project_id.clone()?,
+        })
+    }
+
+    async fn fetch_database_health(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<DatabaseHealthMetrics, String> {
+        let api_base = format!("https://console.neon.tech/api/v2/projects/{}", self.project_id);
+
+        let project = get_json(client.get(&api_base).bearer_auth(&self.api_key), "Neon").await?;
+        let branches = get_json(
+            client.get(format!("{}/branches", api_base)).bearer_auth(&self.api_key),
+            "Neon",
+        ).await?;
+        let endpoints = get_json(
+            client.get(format!("{}/endpoints", api_base)).bearer_auth(&self.api_key),
+            "Neon",
+        ).await.unwrap_or_default();
+
+        let project = &project["project"];
+        let region = project["region_id"].as_str().map(|s| s.to_string());
+        let empty_vec = vec![];
+
+        // A branch's compute endpoint suspends when idle, which is its normal resting state
+        let endpoint_state = |branch_id: &str| {
+            endpoints["endpoints"]
+                .as_array()
+                .unwrap_or(&empty_vec)
+                .iter()
+                .find(|e| e["branch_id"].as_str() == Some(branch_id))
+                .and_then(|e| e["current_state"].as_str())
+        };
+
+        let instances: Vec<DatabaseInstance> = branches["branches"]
+            .as_array()
+            .unwrap_or(&empty_vec)
+            .iter()
+            .filter_map(|branch| {
+                let id = branch["id"].as_str()?;
+                let status = match (branch["current_state"].as_str(), endpoint_state(id)) {
+                    (Some("ready"), Some("idle")) => "sleeping",
+                    (Some("ready"), _) => "ready",
+                    (Some("init"), _) | (Some("resetting"), _) => "provisioning",
+                    _ => "unknown",
+                };
+
+                Some(DatabaseInstance {
+                    name: branch["name"].as_str()?.to_string(),
+                    status: status.to_string(),
+                    region: region.clone(),
+                    primary: branch["default"].as_bool().or_else(|| branch["primary"].as_bool()).unwrap_or(false),
+                    storage_bytes: branch["logical_size"].as_i64(),
+                })
+            })
+            .collect();
+
+        let storage_used_bytes = project["synthetic_storage_size"].as_i64();
+        let compute_hours = project["compute_time_seconds"]
+            .as_f64()
+            .or_else(|| project["cpu_used_sec"].as_f64())
+            .map(|seconds| seconds / 3600.0);
+        let storage_limit_bytes = project["settings"]["quota"]["logical_size_bytes"].as_i64();
+
+        let health = database_health(&instances);
+        println!("Neon: {} branches, {:?} bytes stored ({})", instances.len(), storage_used_bytes, health);
+
+        Ok(DatabaseHealthMetrics {
+            provider: "neon".to_string(),
+            health,
+            instances,
+            storage_used_bytes,
+            storage_limit_bytes,
+            compute_hours,
+            connections: None,
+        })
+    }
+}
+
+// ==========================================
+// MongoDB Atlas Database
+// ==========================================
+
+struct AtlasDatabase {
+    client_id: String, // Service account credentials
+    client_secret: String,
+    group_id: String,  // Atlas project ID
+}
+
+fn normalize_atlas_state(state: &str, paused: bool) -> &'static str {
+    if paused {
+        return "paused";
+    }
+    match state {
+        "IDLE" => "ready",
+        "CREATING" | "UPDATING" | "REPAIRING" => "provisioning",
+        "DELETING" | "DELETED" => "error",
+        _ => "unknown",
+    }
+}
+
+impl AtlasDatabase {
+    async fn access_token(&self, client: &reqwest::Client) -> Result<String, String> {
+        let data = get_json(
+            client
+                .post("https://cloud.mongodb.com/api/oauth/token")
+                .basic_auth(&self.client_id, Some(&self.client_secret))
+                .form(&[("grant_type", "client_credentials")]),
+            "MongoDB Atlas",
+        ).await?;
+
+        data["access_token"]
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| "MongoDB Atlas did not return an access token".to_string())
+    }
+
+    // Latest value of a process measurement over the last hour
+    async fn latest_measurement(
+        &self,
+        client: &reqwest::Client,
+        token: &str,
+        process_id: &str,
+        measurement: &str,
+    ) -> Option<f64> {
+        let data = get_json(
+            client
+                .get(format!(
+                    "https://cloud.mongodb.com/api/atlas/v2/groups/{}/processes/{}/measurements",
+                    self.group_id, process_id
+                ))
+                .query(&[("granularity", "PT5M"), ("period", "PT1H"), ("m", measurement)])
+                .bearer_auth(token)
+                .header("Accept", "application/vnd.atlas.2023-02-01+json"),
+            "MongoDB Atlas",
+        ).await.ok()?;
+
+        data["measurements"][0]["dataPoints"]
+            .as_array()?
+            .iter()
+            .rev()
+            .find_map(|point| point["value"].as_f64())
+    }
+}
+
+impl DatabaseProvider for AtlasDatabase {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(AtlasDatabase {
+            client_id: integration.api_key.clone()?,
+            client_secret: integration.api_secret.clone()?,
+            group_id: integration.project_id.clone()?,
+        })
+    }
+
+    async fn fetch_database_health(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<DatabaseHealthMetrics, String> {
+        let token = self.access_token(client).await?;
+        let api_base = format!("https://cloud.mongodb.com/api/atlas/v2/groups/{}", self.group_id);
+
+        let clusters = get_json(
+            client
+                .get(format!("{}/clusters", api_base))
+                .bearer_auth(&token)
+                .header("Accept", "application/vnd.atlas.2023-02-01+json"),
+            "MongoDB Atlas",
+        ).await?;
+
+        let empty_vec = vec![];
+        let clusters = clusters["results"].as_array().unwrap_or(&empty_vec);
+        let instances: Vec<DatabaseInstance> = clusters
+            .iter()
+            .filter_map(|cluster| {
+                Some(DatabaseInstance {
+                    name: cluster["name"].as_str()?.to_string(),
+                    status: normalize_atlas_state(
+                        cluster["stateName"].as_str().unwrap_or("unknown"),
+                        cluster["paused"].as_bool().unwrap_or(false),
+                    ).to_string(),
+                    region: cluster["replicationSpecs"][0]["regionConfigs"][0]["regionName"]
+                        .as_str()
+                        .map(|s| s.to_string()),
+                    primary: false, // Atlas has no notion of a production cluster
+                    storage_bytes: None,
+                })
+            })
+            .collect();
+
+        let storage_limit_bytes = clusters
+            .iter()
+            .filter_map(|c| c["diskSizeGB"].as_f64())
+            .map(|gb| (gb * 1024.0 * 1024.0 * 1024.0) as i64)
+            .reduce(|a, b| a + b);
+
+        // Connections and data size are per process; the primaries hold the authoritative numbers
+        let processes = get_json(
+            client
+                .get(format!("{}/processes", api_base))
+                .bearer_auth(&token)
+                .header("Accept", "application/vnd.atlas.2023-02-01+json"),
+            "MongoDB Atlas",
+        ).await.unwrap_or_default();
+
+        let mut connections: Option<i64> = None;
+        let mut storage_used_bytes: Option<i64> = None;
+        for process in processes["results"].as_array().unwrap_or(&empty_vec) {
+            if process["typeName"].as_str() != Some("REPLICA_PRIMARY") {
+                continue;
+            }
+            let Some(process_id) = process["id"].as_str() else {
+                continue;
+            };
+
+            if let Some(value) = self.latest_measurement(client, &token, process_id, "CONNECTIONS").await {
+                *connections.get_or_insert(0) += value as i64;
+            }
+            if let Some(value) = self.latest_measurement(client, &token, process_id, "DB_DATA_SIZE_TOTAL").await {
+                *storage_used_bytes.get_or_insert(0) += value as i64;
+            }
+        }
+
+        let health = database_health(&instances);
+        println!("MongoDB Atlas: {} clusters, {:?} connections ({})", instances.len(), connections, health);
+
+        Ok(DatabaseHealthMetrics {
+            provider: "mongodb".to_string(),
+            health,
+            instances,
+            storage_used_bytes,
+            storage_limit_bytes,
+            compute_hours: None,
+            connections,
+        })
+    }
+}
+
+// ==========================================
+// Sentry API Functions
+// ==========================================
+
+const ERROR_TRACKING_DAYS: i64 = 14;
+
+struct SentryClient<'a> {
+    client: &'a reqwest::Client,
+    api_base: String,
+    auth_token: &'a str,
+    organization: &'a str,
+}
+
+impl SentryClient<'_> {
+    async fn get(&self, path: &str, query: &[(&str, String)]) -> Result<reqwest::Response, String> {
+        let response = self
+            .client
+            .get(format!("{}{}", self.api_base, path))
+            .query(query)
+            .bearer_auth(self.auth_token)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("Sentry error: {}", error_text);
+            return Err(format!("Failed to fetch Sentry metrics: {}", status));
+        }
+        Ok(response)
+    }
+
+    // Issue search reports the full match count in X-Hits, so one result is enough
+    // Without a stats period the count covers every matching issue, not just recently seen ones
+    async fn count_issues(&self, project_id: &str, query: &str, stats_period: Option<&str>) -> Option<i64> {
+        let mut params = vec![
+            ("project", project_id.to_string()),
+            ("query", query.to_string()),
+            ("limit", "1".to_string()),
+        ];
+        if let Some(period) = stats_period {
+            params.push(("statsPeriod", period.to_string()));
+        }
+
+        let response = self
+            .get(&format!("/organizations/{}/issues/", self.organization), &params)
+            .await
+            .ok()?;
+
+        response
+            .headers()
+            .get("X-Hits")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+    }
+
+    async fn event_stats(&self, project_id: &str, period: &str, interval: &str) -> serde_json::Value {
+        match self
+            .get(
+                &format!("/organizations/{}/stats_v2/", self.organization),
+                &[
+                    ("project", project_id.to_string()),
+                    ("field", "sum(quantity)".to_string()),
+                    ("category", "error".to_string()),
+                    ("outcome", "accepted".to_string()),
+                    ("interval", interval.to_string()),
+                    ("statsPeriod", period.to_string()),
+                ],
+            )
+            .await
+        {
+            Ok(resp) => resp.json().await.unwrap_or_default(),
+            Err(_) => serde_json::Value::Null,
+        }
+    }
+}
+
+async fn fetch_sentry_metrics(
+    client: &reqwest::Client,
+    auth_token: &str,
+    organization: &str,
+    project: &str,
+    base_url: Option<&str>, // Self-hosted Sentry, e.g. "https://sentry.example.com"
+) -> Result<ErrorTrackingMetrics, String> {
+    let sentry = SentryClient {
+        client,
+        api_base: format!("{}/api/0", base_url.unwrap_or("https://sentry.io").trim_end_matches('/')),
+        auth_token,
+        organization,
+    };
+    let stats_period = format!("{}d", ERROR_TRACKING_DAYS);
+
+    // Organization-level endpoints filter by numeric project ID rather than slug
+    let project_info: serde_json::Value = sentry
+        .get(&format!("/projects/{}/{}/", organization, project), &[])
+        .await?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+    let project_id = project_info["id"].as_str().ok_or("Sentry project has no ID")?.to_string();
+
+    let unresolved_issues = sentry.count_issues(&project_id, "is:unresolved", None).await;
+    let new_issues_24h = sentry.count_issues(&project_id, "firstSeen:-24h", Some("24h")).await;
+
+    let top_issues: Vec<ErrorIssue> = match sentry
+        .get(
+            &format!("/organizations/{}/issues/", organization),
+            &[
+                ("project", project_id.clone()),
+                ("query", "is:unresolved".to_string()),
+                ("sort", "freq".to_string()),
+                ("statsPeriod", stats_period.clone()),
+                ("limit", "10".to_string()),
+            ],
+        )
+        .await
+    {
+        Ok(resp) => resp
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|data| data.as_array().cloned())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|issue| {
+                Some(ErrorIssue {
+                    id: issue["shortId"].as_str().or_else(|| issue["id"].as_str())?.to_string(),
+                    title: issue["title"].as_str().unwrap_or("Unknown error").to_string(),
+                    culprit: issue["culprit"].as_str().filter(|c| !c.is_empty()).map(|s| s.to_string()),
+                    level: issue["level"].as_str().unwrap_or("error").to_string(),
+                    // Counts come back as strings
+                    count: json_number(&issue["count"]) as i64,
+                    user_count: json_number(&issue["userCount"]) as i64,
+                    first_seen: issue["firstSeen"].as_str().map(|s| s.to_string()),
+                    last_seen: issue["lastSeen"].as_str().map(|s| s.to_string()),
+                    url: issue["permalink"].as_str().map(|s| s.to_string()),
+                })
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    // Accepted error events, per day over the window and in total over the last 24h
+    let stats = sentry.event_stats(&project_id, &stats_period, "1d").await;
+    let daily_events: Vec<DailyCount> = stats["intervals"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .zip(stats["groups"][0]["series"]["sum(quantity)"].as_array().into_iter().flatten())
+        .filter_map(|(interval, count)| {
+            Some(DailyCount {
+                date: normalize_analytics_date(interval.as_str()?),
+                count: count.as_i64().unwrap_or(0),
+            })
+        })
+        .collect();
+    let events_24h = sentry.event_stats(&project_id, "24h", "1h").await["groups"][0]["totals"]["sum(quantity)"]
+        .as_i64()
+        .unwrap_or(0);
+
+    // Release health needs session tracking in the SDK; without it these come back empty
+    let session_query = |group_by_release: bool| {
+        let mut query = vec![
+            ("project", project_id.clone()),
+            ("field", "sum(session)".to_string()),
+            ("field", "crash_free_rate(session)".to_string()),
+            ("statsPeriod", stats_period.clone()),
+            ("interval", "1d".to_string()),
+        ];
+        if group_by_release {
+            query.push(("groupBy", "release".to_string()));
+            query.push(("orderBy", "-sum(session)".to_string()));
+            query.push(("per_page", "5".to_string()));
+        }
+        query
+    };
+    let sessions_path = format!("/organizations/{}/sessions/", organization);
+    let crash_free = |totals: &serde_json::Value| {
+        totals["crash_free_rate(session)"].as_f64().map(|rate| rate * 100.0)
+    };
+
+    let crash_free_rate = match sentry.get(&sessions_path, &session_query(false)).await {
+        Ok(resp) => resp
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|data| crash_free(&data["groups"][0]["totals"])),
+        Err(_) => None,
+    };
+
+    let releases: Vec<ReleaseHealth> = match sentry.get(&sessions_path, &session_query(true)).await {
+        Ok(resp) => resp
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|data| data["groups"].as_array().cloned())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|group| {
+                Some(ReleaseHealth {
+                    version: group["by"]["release"].as_str()?.to_string(),
+                    sessions: group["totals"]["sum(session)"].as_i64().unwrap_or(0),
+                    crash_free_rate: crash_free(&group["totals"]),
+                })
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    println!(
+        "Sentry: {:?} unresolved issues, {:?} new in 24h, {} events in 24h, {:?}% crash-free",
+        unresolved_issues, new_issues_24h, events_24h, crash_free_rate
+    );
+
+    Ok(ErrorTrackingMetrics {
+        provider: "sentry".to_string(),
+        unresolved_issues,
+        new_issues_24h,
+        events_24h,
+        daily_events,
+        crash_free_rate,
+        releases,
+        top_issues,
+        sessions_24h: None,
+        friction: None,
+    })
+}
+
+// ==========================================
+// LogRocket API Functions
+// ==========================================
+
+// LogRocket has no fixed catalogue of metric names: each series is a metric the
+// user saves under Metrics in the dashboard, addressed by its ID. The integration
+// stores those IDs by role ("errors=123, sessions=456, ...") so nothing here
+// depends on guessed names.
+fn parse_metric_ids(spec: &str) -> HashMap<String, String> {
+    spec.split(',')
+        .filter_map(|pair| {
+            let (role, id) = pair.split_once('=')?;
+            let (role, id) = (role.trim(), id.trim());
+            (!role.is_empty() && !id.is_empty()).then(|| (role.to_lowercase(), id.to_string()))
+        })
+        .collect()
+}
+
+struct LogRocketClient<'a> {
+    client: &'a reqwest::Client,
+    api_base: String, // .../v1/orgs/{org}/apps/{app}
+    api_key: &'a str,
+}
+
+impl LogRocketClient<'_> {
+    // Daily totals for one saved metric over the error tracking window
+    async fn daily_metric(&self, metric_id: &str) -> Result<Vec<DailyCount>, String> {
+        let end = chrono::Utc::now();
+        let start = end - chrono::Duration::days(ERROR_TRACKING_DAYS - 1);
+
+        let response = self
+            .client
+            .post(format!("{}/metrics/timeseries/", self.api_base))
+            .header("Authorization", format!("Token {}", self.api_key))
+            .json(&serde_json::json!({
+                "metric": metric_id,
+                "interval": "day",
+                "start": start.format("%Y-%m-%d").to_string(),
+                "end": end.format("%Y-%m-%d").to_string(),
+            }))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("LogRocket error: {}", error_text);
+            return Err(format!("Failed to fetch LogRocket metric {}: {}", metric_id, status));
+        }
+
+        let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+        Ok(data["data"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|point| {
+                Some(DailyCount {
+                    date: normalize_analytics_date(point["date"].as_str()?),
+                    count: json_number(&point["value"]) as i64,
+                })
+            })
+            .collect())
+    }
+
+    // Latest day's value, or None when the metric isn't configured or fails to load
+    async fn latest(&self, metric_id: Option<&String>) -> Option<i64> {
+        let series = self.daily_metric(metric_id?).await.ok()?;
+        series.last().map(|d| d.count)
+    }
+}
+
+async fn fetch_logrocket_metrics(
+    client: &reqwest::Client,
+    api_key: &str,
+    organization: &str,
+    app: &str,
+    metric_ids: &str,
+) -> Result<ErrorTrackingMetrics, String> {
+    let metric_ids = parse_metric_ids(metric_ids);
+    let logrocket = LogRocketClient {
+        client,
+        api_base: format!("https://api.logrocket.com/v1/orgs/{}/apps/{}", organization, app),
+        api_key,
+    };
+
+    // Errors are the core series, so a failure here fails the integration
+    let errors_id = metric_ids
+        .get("errors")
+        .ok_or("LogRocket metric IDs must include errors=<id>")?;
+    let daily_events = logrocket.daily_metric(errors_id).await?;
+    let events_24h = daily_events.last().map(|d| d.count).unwrap_or(0);
+
+    let sessions_24h = logrocket.latest(metric_ids.get("sessions")).await;
+    let friction = FrictionSignals {
+        rage_clicks_24h: logrocket.latest(metric_ids.get("rage_clicks")).await,
+        dead_clicks_24h: logrocket.latest(metric_ids.get("dead_clicks")).await,
+        error_sessions_24h: logrocket.latest(metric_ids.get("error_sessions")).await,
+    };
+    let has_friction = friction.rage_clicks_24h.is_some()
+        || friction.dead_clicks_24h.is_some()
+        || friction.error_sessions_24h.is_some();
+
+    // Issues are grouped errors ranked by affected sessions
+    let top_issues: Vec<ErrorIssue> = match client
+        .get(format!("{}/issues/", logrocket.api_base))
+        .query(&[("limit", "10"), ("sort", "sessions")])
+        .header("Authorization", format!("Token {}", api_key))
+        .send()
+        .await
+    {
+        Ok(resp) if resp.status().is_success() => resp
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|data| data["results"].as_array().cloned())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|issue| {
+                Some(ErrorIssue {
+                    id: issue["id"].as_str()?.to_string(),
+                    title: issue["title"].as_str().unwrap_or("Unknown error").to_string(),
+                    culprit: issue["url"].as_str().map(|s| s.to_string()),
+                    level: issue["type"].as_str().unwrap_or("error").to_string(),
+                    count: json_number(&issue["count"]) as i64,
+                    user_count: json_number(&issue["userCount"]) as i64,
+                    first_seen: issue["firstSeen"].as_str().map(|s| s.to_string()),
+                    last_seen: issue["lastSeen"].as_str().map(|s| s.to_string()),
+                    url: issue["permalink"].as_str().map(|s| s.to_string()),
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    println!(
+        "LogRocket: {} errors in the last day, sessions {:?}, rage clicks {:?}",
+        events_24h, sessions_24h, friction.rage_clicks_24h
+    );
+
+    Ok(ErrorTrackingMetrics {
+        provider: "logrocket".to_string(),
+        unresolved_issues: None,
+        new_issues_24h: None,
+        events_24h,
+        daily_events,
+        crash_free_rate: None,
+        releases: Vec::new(),
+        top_issues,
+        sessions_24h,
+        friction: has_friction.then_some(friction),
+    })
+}
+
+// ==========================================
+// Email Delivery Functions
+// ==========================================
+
+fn email_delivery_metrics(
+    provider: &str,
+    today: EmailDeliveryCounts,
+    last_7d: EmailDeliveryCounts,
+    domains: Vec<EmailDomain>,
+) -> EmailDeliveryMetrics {
+    let rate = |count: i64| {
+        (last_7d.sent > 0).then(|| count as f64 / last_7d.sent as f64 * 100.0)
+    };
+
+    EmailDeliveryMetrics {
+        provider: provider.to_string(),
+        delivery_rate: rate(last_7d.delivered),
+        bounce_rate: rate(last_7d.bounced),
+        today,
+        last_7d,
+        domains,
+    }
+}
+
+// ==========================================
+// Resend API Functions
+// ==========================================
+
+// Resend has no stats endpoint, so emails are paged through and counted by their latest event
+const RESEND_MAX_PAGES: usize = 20;
+
+async fn fetch_resend_metrics(
+    client: &reqwest::Client,
+    api_key: &str,
+) -> Result<EmailDeliveryMetrics, String> {
+    let now = chrono::Utc::now();
+    // Same calendar-day window as SendGrid's daily stats
+    let midnight = now.date_naive().and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+    let week_ago = now - chrono::Duration::days(7);
+
+    let mut today = EmailDeliveryCounts::default();
+    let mut last_7d = EmailDeliveryCounts::default();
+    let mut after: Option<String> = None;
+
+    'pages: for _ in 0..RESEND_MAX_PAGES {
+        let mut request = client
+            .get("https://api.resend.com/emails")
+            .bearer_auth(api_key)
+            .query(&[("limit", "100")]);
+        if let Some(cursor) = &after {
+            request = request.query(&[("after", cursor)]);
+        }
+
+        let data = get_json(request, "Resend").await?;
+        let emails = data["data"].as_array().cloned().unwrap_or_default();
+
+        for email in &emails {
+            // Newest first, so stop at the first email outside the window
+            let Some(created_at) = parse_timestamp(&email["created_at"]) else { continue };
+            if created_at < week_ago {
+                break 'pages;
+            }
+
+            let last_event = email["last_event"].as_str().unwrap_or("sent");
+            let delivered = matches!(last_event, "delivered" | "opened" | "clicked" | "complained");
+            let opened = matches!(last_event, "opened" | "clicked");
+
+            for counts in std::iter::once(&mut last_7d).chain((created_at >= midnight).then_some(&mut today)) {
+                counts.sent += 1;
+                counts.delivered += delivered as i64;
+                counts.bounced += (last_event == "bounced") as i64;
+                counts.complained += (last_event == "complained") as i64;
+                counts.opened += opened as i64;
+            }
+        }
+
+        if !data["has_more"].as_bool().unwrap_or(false) {
+            break;
+        }
+        after = emails.last().and_then(|e| e["id"].as_str()).map(|id| id.to_string());
+        if after.is_none() {
+            break;
+        }
+    }
+
+    let domains = match get_json(
+        client.get("https://api.resend.com/domains").bearer_auth(api_key),
+        "Resend",
+    ).await {
+        Ok(data) => data["data"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|domain| {
+                let status = domain["status"].as_str().unwrap_or("unknown");
+                Some(EmailDomain {
+                    name: domain["name"].as_str()?.to_string(),
+                    status: status.to_string(),
+                    verified: status == "verified",
+                })
+            })
+            .collect(),
+        // Sending-only keys can't list domains
+        Err(_) => Vec::new(),
+    };
+
+    println!("Resend: {} emails sent in the last 7 days", last_7d.sent);
+
+    Ok(email_delivery_metrics("resend", today, last_7d, domains))
+}
+
+// ==========================================
+// SendGrid API Functions
+// ==========================================
+
+async fn fetch_sendgrid_metrics(
+    client: &reqwest::Client,
+    api_key: &str,
+) -> Result<EmailDeliveryMetrics, String> {
+    let start_date = (chrono::Utc::now() - chrono::Duration::days(6)).format("%Y-%m-%d").to_string();
+
+    let stats = get_json(
+        client
+            .get("https://api.sendgrid.com/v3/stats")
+            .bearer_auth(api_key)
+            .query(&[("start_date", start_date.as_str()), ("aggregated_by", "day")]),
+        "SendGrid",
+    ).await?;
+
+    // One entry per day, oldest first: [{ date, stats: [{ metrics: { requests, delivered, ... } }] }]
+    let days: Vec<EmailDeliveryCounts> = stats
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|day| {
+            let metrics = &day["stats"][0]["metrics"];
+            let count = |key: &str| metrics[key].as_i64().unwrap_or(0);
+            EmailDeliveryCounts {
+                sent: count("requests"),
+                delivered: count("delivered"),
+                bounced: count("bounces"),
+                complained: count("spam_reports"),
+                opened: count("unique_opens"),
+            }
+        })
+        .collect();
+
+    let last_7d = days.iter().fold(EmailDeliveryCounts::default(), |mut total, day| {
+        total.sent += day.sent;
+        total.delivered += day.delivered;
+        total.bounced += day.bounced;
+        total.complained += day.complained;
+        total.opened += day.opened;
+        total
+    });
+    // The last entry is the current UTC day so far
+    let today = days.into_iter().last().unwrap_or_default();
+
+    let domains = match get_json(
+        client.get("https://api.sendgrid.com/v3/whitelabel/domains").bearer_auth(api_key),
+        "SendGrid",
+    ).await {
+        Ok(data) => data
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|domain| {
+                let verified = domain["valid"].as_bool().unwrap_or(false);
+                Some(EmailDomain {
+                    name: domain["domain"].as_str()?.to_string(),
+                    status: if verified { "verified" } else { "pending" }.to_string(),
+                    verified,
+                })
+            })
+            .collect(),
+        // Keys without the whitelabel scope can't list domains
+        Err(_) => Vec::new(),
+    };
+
+    println!("SendGrid: {} emails sent in the last 7 days", last_7d.sent);
+
+    Ok(email_delivery_metrics("sendgrid", today, last_7d, domains))
+}
+
+// ==========================================
+// Newsletter Functions
+// ==========================================
+
+const NEWSLETTER_DAYS: i64 = 30;
+
+// Walks back from today's total, undoing each day's net change
+fn subscriber_growth_series(total: i64, net_by_date: &HashMap<String, i64>) -> Vec<DailyCount> {
+    let today = chrono::Utc::now();
+    let mut size = total;
+    let mut series: Vec<DailyCount> = (0..NEWSLETTER_DAYS)
+        .map(|days_ago| {
+            let date = (today - chrono::Duration::days(days_ago)).format("%Y-%m-%d").to_string();
+            let point = DailyCount { date: date.clone(), count: size };
+            size -= net_by_date.get(&date).copied().unwrap_or(0);
+            point
+        })
+        .collect();
+    series.reverse();
+    series
+}
+
+// ==========================================
+// ConvertKit API Functions
+// ==========================================
+
+const CONVERTKIT_MAX_PAGES: i64 = 20;
+// Recent broadcasts checked for the latest one that has actually gone out
+const CONVERTKIT_BROADCAST_LOOKBACK: usize = 10;
+
+// Subscriber list filtered by `params`; returns the matching total and the
+// dates (from `date_field`) of as many subscribers as fit in the page limit
+async fn fetch_convertkit_subscribers(
+    client: &reqwest::Client,
+    api_secret: &str,
+    params: &[(&str, &str)],
+    date_field: &str,
+) -> Result<(i64, Vec<chrono::DateTime<chrono::Utc>>), String> {
+    let mut dates = Vec::new();
+    let mut page = 1;
+
+    let total = loop {
+        let page_param = page.to_string();
+        let data = get_json(
+            client
+                .get("https://api.convertkit.com/v3/subscribers")
+                .query(&[("api_secret", api_secret), ("page", page_param.as_str())])
+                .query(params),
+            "ConvertKit",
+        ).await?;
+
+        dates.extend(
+            data["subscribers"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|s| parse_timestamp(&s[date_field])),
+        );
+
+        let total_pages = data["total_pages"].as_i64().unwrap_or(1);
+        if page >= total_pages || page >= CONVERTKIT_MAX_PAGES {
+            break data["total_subscribers"].as_i64().unwrap_or(0);
+        }
+        page += 1;
+    };
+
+    Ok((total, dates))
+}
+
+async fn fetch_convertkit_metrics(
+    client: &reqwest::Client,
+    api_secret: &str,
+) -> Result<NewsletterMetrics, String> {
+    let since = (chrono::Utc::now() - chrono::Duration::days(NEWSLETTER_DAYS - 1))
+        .format("%Y-%m-%d")
+        .to_string();
+
+    // The first page carries the overall total
+    let total_subscribers = get_json(
+        client
+            .get("https://api.convertkit.com/v3/subscribers")
+            .query(&[("api_secret", api_secret), ("subscriber_state", "active")]),
+        "ConvertKit",
+    ).await?["total_subscribers"]
+        .as_i64()
+        .unwrap_or(0);
+    // The subscriber list defaults to active subscribers, so anyone who joined
+    // and already cancelled within the window is counted separately
+    let (active_new_30d, mut new_dates) = fetch_convertkit_subscribers(
+        client,
+        api_secret,
+        &[("from", since.as_str())],
+        "created_at",
+    ).await?;
+    let (cancelled_new_30d, cancelled_new_dates) = fetch_convertkit_subscribers(
+        client,
+        api_secret,
+        &[("subscriber_state", "cancelled"), ("from", since.as_str())],
+        "created_at",
+    ).await
+    .unwrap_or_default();
+    let new_subscribers_30d = active_new_30d + cancelled_new_30d;
+    new_dates.extend(cancelled_new_dates);
+    // A cancelled subscriber's last update is their unsubscribe
+    let (unsubscribed_30d, unsubscribe_dates) = fetch_convertkit_subscribers(
+        client,
+        api_secret,
+        &[("subscriber_state", "cancelled"), ("updated_from", since.as_str())],
+        "updated_at",
+    ).await
+    .unwrap_or_default();
+
+    let mut net_by_date: HashMap<String, i64> = HashMap::new();
+    for date in &new_dates {
+        *net_by_date.entry(date.format("%Y-%m-%d").to_string()).or_insert(0) += 1;
+    }
+    for date in &unsubscribe_dates {
+        *net_by_date.entry(date.format("%Y-%m-%d").to_string()).or_insert(0) -= 1;
+    }
+
+    // Broadcasts come back oldest first and include drafts and scheduled sends,
+    // which only the broadcast details can tell apart
+    let broadcasts = get_json(
+        client
+            .get("https://api.convertkit.com/v3/broadcasts")
+            .query(&[("api_secret", api_secret)]),
+        "ConvertKit",
+    ).await
+    .unwrap_or_default();
+    let mut recent: Vec<&serde_json::Value> = broadcasts["broadcasts"].as_array().into_iter().flatten().collect();
+    recent.sort_by_key(|b| std::cmp::Reverse(parse_timestamp(&b["created_at"])));
+
+    let now = chrono::Utc::now();
+    let mut latest_broadcast = None;
+    for candidate in recent.into_iter().take(CONVERTKIT_BROADCAST_LOOKBACK) {
+        let Ok(details) = get_json(
+            client
+                .get(format!("https://api.convertkit.com/v3/broadcasts/{}", candidate["id"]))
+                .query(&[("api_secret", api_secret)]),
+            "ConvertKit",
+        ).await else { continue };
+        let broadcast = &details["broadcast"];
+        let sent_at = parse_timestamp(&broadcast["published_at"]).or_else(|| parse_timestamp(&broadcast["send_at"]));
+        if sent_at.is_some_and(|at| at <= now) {
+            latest_broadcast = Some((broadcast.clone(), sent_at));
+            break;
+        }
+    }
+
+    let latest_campaign = match latest_broadcast {
+        Some((broadcast, sent_at)) => {
+            let id = broadcast["id"].to_string();
+            let stats = get_json(
+                client
+                    .get(format!("https://api.convertkit.com/v3/broadcasts/{}/stats", id))
+                    .query(&[("api_secret", api_secret)]),
+                "ConvertKit",
+            ).await
+            .unwrap_or_default();
+            let stats = &stats["broadcast"]["stats"];
+
+            Some(NewsletterCampaign {
+                id,
+                subject: broadcast["subject"].as_str().unwrap_or("Untitled").to_string(),
+                sent_at: sent_at.map(|at| at.to_rfc3339()),
+                recipients: stats["recipients"].as_i64().unwrap_or(0),
+                open_rate: stats["open_rate"].as_f64(),
+                click_rate: stats["click_rate"].as_f64(),
+            })
+        }
+        None => None,
+    };
+
+    println!(
+        "ConvertKit: {} subscribers, {} new in {} days",
+        total_subscribers, new_subscribers_30d, NEWSLETTER_DAYS
+    );
+
+    Ok(NewsletterMetrics {
+        provider: "convertkit".to_string(),
+        total_subscribers,
+        new_subscribers_30d,
+        unsubscribed_30d,
+        subscriber_growth: subscriber_growth_series(total_subscribers, &net_by_date),
+        latest_campaign,
+    })
+}
+
+// ==========================================
+// Mailchimp API Functions
+// ==========================================
+
+async fn fetch_mailchimp_metrics(
+    client: &reqwest::Client,
+    api_key: &str,
+    list_id: Option<&str>, // Defaults to the account's first audience
+) -> Result<NewsletterMetrics, String> {
+    // Keys end in the data center, e.g. "abc123-us21"
+    let dc = api_key
+        .rsplit_once('-')
+        .map(|(_, dc)| dc)
+        .ok_or("Mailchimp API key is missing its data center suffix")?;
+    let api_base = format!("https://{}.api.mailchimp.com/3.0", dc);
+    let get = |path: String| client.get(format!("{}{}", api_base, path)).basic_auth("pulse", Some(api_key));
+
+    let list = match list_id {
+        Some(id) => get_json(get(format!("/lists/{}", id)), "Mailchimp").await?,
+        None => get_json(get("/lists?count=1&sort_field=date_created&sort_dir=ASC".to_string()), "Mailchimp")
+            .await?["lists"][0]
+            .clone(),
+    };
+    let list_id = list["id"].as_str().ok_or("No Mailchimp audience found")?.to_string();
+    let total_subscribers = list["stats"]["member_count"].as_i64().unwrap_or(0);
+
+    // Daily subscribe/unsubscribe counts, newest first
+    let activity = get_json(
+        get(format!("/lists/{}/activity?count={}", list_id, NEWSLETTER_DAYS)),
+        "Mailchimp",
+    ).await?;
+
+    let mut net_by_date: HashMap<String, i64> = HashMap::new();
+    let mut new_subscribers_30d = 0;
+    let mut unsubscribed_30d = 0;
+    for day in activity["activity"].as_array().into_iter().flatten() {
+        let Some(date) = day["day"].as_str() else { continue };
+        let subs = day["subs"].as_i64().unwrap_or(0);
+        let unsubs = day["unsubs"].as_i64().unwrap_or(0);
+        new_subscribers_30d += subs;
+        unsubscribed_30d += unsubs;
+        net_by_date.insert(date.to_string(), subs - unsubs);
+    }
+
+    let campaigns = get_json(
+        get(format!(
+            "/campaigns?list_id={}&status=sent&sort_field=send_time&sort_dir=DESC&count=1",
+            list_id
+        )),
+        "Mailchimp",
+    ).await
+    .unwrap_or_default();
+    let latest_campaign = campaigns["campaigns"].get(0).map(|campaign| {
+        let report = &campaign["report_summary"];
+        NewsletterCampaign {
+            id: campaign["id"].as_str().unwrap_or_default().to_string(),
+            subject: campaign["settings"]["subject_line"].as_str().unwrap_or("Untitled").to_string(),
+            sent_at: campaign["send_time"].as_str().map(|s| s.to_string()),
+            recipients: campaign["emails_sent"].as_i64().unwrap_or(0),
+            // Mailchimp reports rates as fractions
+            open_rate: report["open_rate"].as_f64().map(|r| r * 100.0),
+            click_rate: report["click_rate"].as_f64().map(|r| r * 100.0),
+        }
+    });
+
+    println!(
+        "Mailchimp: {} subscribers, {} new in {} days",
+        total_subscribers, new_subscribers_30d, NEWSLETTER_DAYS
+    );
+
+    Ok(NewsletterMetrics {
+        provider: "mailchimp".to_string(),
+        total_subscribers,
+        new_subscribers_30d,
+        unsubscribed_30d,
+        subscriber_growth: subscriber_growth_series(total_subscribers, &net_by_date),
+        latest_campaign,
+    })
+}
+
+// ==========================================
+// Support Functions
+// ==========================================
+
+const SUPPORT_DAYS: i64 = 14;
+const SUPPORT_MAX_PAGES: usize = 20;
+
+fn support_metrics(
+    provider: &str,
+    open_conversations: i64,
+    unassigned_conversations: Option<i64>,
+    created: &[chrono::DateTime<chrono::Utc>],
+    mut first_response_seconds: Vec<f64>,
+) -> SupportMetrics {
+    let now = chrono::Utc::now();
+    let mut counts_by_date: HashMap<String, i64> = HashMap::new();
+    for created_at in created {
+        *counts_by_date.entry(created_at.format("%Y-%m-%d").to_string()).or_insert(0) += 1;
+    }
+    let daily_new_conversations: Vec<DailyCount> = (0..SUPPORT_DAYS)
+        .rev()
+        .map(|days_ago| {
+            let date = (now - chrono::Duration::days(days_ago)).format("%Y-%m-%d").to_string();
+            let count = counts_by_date.get(&date).copied().unwrap_or(0);
+            DailyCount { date, count }
+        })
+        .collect();
+
+    first_response_seconds.sort_by(|a, b| a.total_cmp(b));
+    let median_first_response_seconds = match first_response_seconds.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(first_response_seconds[n / 2]),
+        n => Some((first_response_seconds[n / 2 - 1] + first_response_seconds[n / 2]) / 2.0),
+    };
+
+    SupportMetrics {
+        provider: provider.to_string(),
+        open_conversations,
+        unassigned_conversations,
+        new_conversations_7d: created
+            .iter()
+            .filter(|dt| **dt > now - chrono::Duration::days(7))
+            .count() as i64,
+        daily_new_conversations,
+        median_first_response_seconds,
+    }
+}
+
+// ==========================================
+// Intercom API Functions
+// ==========================================
+
+async fn search_intercom_conversations(
+    client: &reqwest::Client,
+    access_token: &str,
+    query: serde_json::Value,
+    pagination: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    get_json(
+        client
+            .post("https://api.intercom.io/conversations/search")
+            .bearer_auth(access_token)
+            .header("Intercom-Version", "2.11")
+            .json(&serde_json::json!({ "query": query, "pagination": pagination })),
+        "Intercom",
+    ).await
+}
+
+async fn fetch_intercom_metrics(
+    client: &reqwest::Client,
+    access_token: &str,
+) -> Result<SupportMetrics, String> {
+    let open = serde_json::json!({ "field": "open", "operator": "=", "value": true });
+    let count_only = serde_json::json!({ "per_page": 1 });
+
+    let open_conversations = search_intercom_conversations(client, access_token, open.clone(), count_only.clone())
+        .await?["total_count"]
+        .as_i64()
+        .unwrap_or(0);
+
+    // Intercom uses 0 for "no admin assigned"
+    let unassigned_conversations = search_intercom_conversations(
+        client,
+        access_token,
+        serde_json::json!({
+            "operator": "AND",
+            "value": [open, { "field": "admin_assignee_id", "operator": "=", "value": 0 }],
+        }),
+        count_only,
+    ).await
+    .ok()
+    .and_then(|data| data["total_count"].as_i64());
+
+    let since = (chrono::Utc::now() - chrono::Duration::days(SUPPORT_DAYS)).timestamp();
+    let mut created = Vec::new();
+    let mut first_response_seconds = Vec::new();
+    let mut starting_after: Option<String> = None;
+
+    for _ in 0..SUPPORT_MAX_PAGES {
+        let mut pagination = serde_json::json!({ "per_page": 150 });
+        if let Some(cursor) = &starting_after {
+            pagination["starting_after"] = serde_json::json!(cursor);
+        }
+
+        let data = search_intercom_conversations(
+            client,
+            access_token,
+            serde_json::json!({ "field": "created_at", "operator": ">", "value": since }),
+            pagination,
+        ).await?;
+
+        for conversation in data["conversations"].as_array().into_iter().flatten() {
+            // Intercom timestamps are unix seconds
+            if let Some(created_at) = conversation["created_at"]
+                .as_i64()
+                .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+            {
+                created.push(created_at);
+            }
+            if let Some(seconds) = conversation["statistics"]["time_to_admin_reply"].as_f64() {
+                first_response_seconds.push(seconds);
+            }
+        }
+
+        starting_after = data["pages"]["next"]["starting_after"].as_str().map(|s| s.to_string());
+        if starting_after.is_none() {
+            break;
+        }
+    }
+
+    println!(
+        "Intercom: {} open conversations, {} new in {} days",
+        open_conversations,
+        created.len(),
+        SUPPORT_DAYS
+    );
+
+    Ok(support_metrics(
+        "intercom",
+        open_conversations,
+        unassigned_conversations,
+        &created,
+        first_response_seconds,
+    ))
+}
+
+// ==========================================
+// Crisp API Functions
+// ==========================================
+
+// Crisp reports no totals, so a filtered conversation search is paged through and counted
+async fn count_crisp_conversations(
+    client: &reqwest::Client,
+    identifier: &str,
+    key: &str,
+    website_id: &str,
+    filters: &[(&str, &str)],
+) -> Result<i64, String> {
+    let mut total = 0;
+    for page in 1..=SUPPORT_MAX_PAGES {
+        let data = get_json(
+            client
+                .get(format!("https://api.crisp.chat/v1/website/{}/conversations/{}", website_id, page))
+                .query(filters)
+                .basic_auth(identifier, Some(key))
+                .header("X-Crisp-Tier", "plugin"),
+            "Crisp",
+        ).await?;
+
+        let count = data["data"].as_array().map(|list| list.len()).unwrap_or(0);
+        if count == 0 {
+            break;
+        }
+        total += count as i64;
+    }
+    Ok(total)
+}
+
+async fn fetch_crisp_metrics(
+    client: &reqwest::Client,
+    identifier: &str,
+    key: &str,
+    website_id: &str,
+) -> Result<SupportMetrics, String> {
+    let since = chrono::Utc::now() - chrono::Duration::days(SUPPORT_DAYS);
+    let mut created = Vec::new();
+
+    // Open conversations can be inactive for longer than the window, so they're searched for directly
+    let open_conversations =
+        count_crisp_conversations(client, identifier, key, website_id, &[("filter_not_resolved", "1")]).await?;
+    let unassigned_conversations = count_crisp_conversations(
+        client,
+        identifier,
+        key,
+        website_id,
+        &[("filter_not_resolved", "1"), ("filter_unassigned", "1")],
+    ).await
+    .ok();
+
+    // Conversations come back most recently active first, 20 per page
+    for page in 1..=SUPPORT_MAX_PAGES {
+        let data = get_json(
+            client
+                .get(format!("https://api.crisp.chat/v1/website/{}/conversations/{}", website_id, page))
+                .basic_auth(identifier, Some(key))
+                .header("X-Crisp-Tier", "plugin"),
+            "Crisp",
+        ).await?;
+
+        let conversations = data["data"].as_array().cloned().unwrap_or_default();
+        if conversations.is_empty() {
+            break;
+        }
+
+        let mut any_recent = false;
+        for conversation in &conversations {
+            // Crisp timestamps are milliseconds
+            let created_at = parse_timestamp(&conversation["created_at"]);
+            let updated_at = parse_timestamp(&conversation["updated_at"]);
+            any_recent |= updated_at.is_some_and(|dt| dt > since);
+
+            if let Some(created_at) = created_at.filter(|dt| *dt > since) {
+                created.push(created_at);
+            }
+        }
+
+        // Pages are ordered by activity, so once a whole page is stale, so is the rest
+        if !any_recent {
+            break;
+        }
+    }
+
+    println!(
+        "Crisp: {} open conversations, {} new in {} days",
+        open_conversations,
+        created.len(),
+        SUPPORT_DAYS
+    );
+
+    // Crisp's REST API has no response-time data
+    Ok(support_metrics(
+        "crisp",
+        open_conversations,
+        unassigned_conversations,
+        &created,
+        Vec::new(),
+    ))
+}
+
+// ==========================================
+// Discord API Functions
+// ==========================================
+
+const DISCORD_GROWTH_DAYS: i64 = 30;
+const DISCORD_MAX_MEMBER_PAGES: usize = 10; // 1000 members per page
+
+async fn fetch_discord_metrics(
+    client: &reqwest::Client,
+    bot_token: &str,
+    guild_id: &str,
+    base_url: Option<&str>, // Replaces https://discord.com, e.g. for a stand-in server
+) -> Result<CommunityMetrics, String> {
+    let api_base = format!("{}/api/v10", base_url.unwrap_or("https://discord.com").trim_end_matches('/'));
+    let auth = format!("Bot {}", bot_token);
+
+    let guild = get_json(
+        client
+            .get(format!("{}/guilds/{}", api_base, guild_id))
+            .query(&[("with_counts", "true")])
+            .header("Authorization", &auth),
+        "Discord",
+    ).await?;
+
+    let total_members = guild["approximate_member_count"].as_i64().unwrap_or(0);
+    let online_members = guild["approximate_presence_count"].as_i64().unwrap_or(0);
+
+    // Listing members needs the privileged Server Members intent; without it growth stays None.
+    // Growth also stays None for servers too large to list within the page cap, since a
+    // partial list (ordered by user ID, not join date) would undercount recent joins.
+    let mut join_dates = Vec::new();
+    let mut after = "0".to_string();
+    let mut members_listed = false;
+    for _ in 0..DISCORD_MAX_MEMBER_PAGES {
+        let page = match get_json(
+            client
+                .get(format!("{}/guilds/{}/members", api_base, guild_id))
+                .query(&[("limit", "1000"), ("after", after.as_str())])
+                .header("Authorization", &auth),
+            "Discord",
+        ).await {
+            Ok(page) => page,
+            Err(_) => break,
+        };
+
+        let members = page.as_array().cloned().unwrap_or_default();
+        join_dates.extend(members.iter().filter_map(|m| parse_timestamp(&m["joined_at"])));
+
+        match members.last().and_then(|m| m["user"]["id"].as_str()) {
+            Some(id) if members.len() == 1000 => after = id.to_string(),
+            _ => {
+                members_listed = true;
+                break;
+            }
+        }
+    }
+
+    let (new_members_7d, new_members_30d, daily_joins) = if members_listed {
+        let now = chrono::Utc::now();
+        let joined_within = |days: i64| {
+            join_dates
+                .iter()
+                .filter(|dt| **dt > now - chrono::Duration::days(days))
+                .count() as i64
+        };
+
+        let mut joins_by_date: HashMap<String, i64> = HashMap::new();
+        for joined_at in &join_dates {
+            *joins_by_date.entry(joined_at.format("%Y-%m-%d").to_string()).or_insert(0) += 1;
+        }
+        let daily_joins: Vec<DailyCount> = (0..DISCORD_GROWTH_DAYS)
+            .rev()
+            .map(|days_ago| {
+                let date = (now - chrono::Duration::days(days_ago)).format("%Y-%m-%d").to_string();
+                let count = joins_by_date.get(&date).copied().unwrap_or(0);
+                DailyCount { date, count }
+            })
+            .collect();
+
+        (Some(joined_within(7)), Some(joined_within(DISCORD_GROWTH_DAYS)), Some(daily_joins))
+    } else {
+        (None, None, None)
+    };
+
+    println!("Discord: {} members, {} online", total_members, online_members);
+
+    Ok(CommunityMetrics {
+        provider: "discord".to_string(),
+        name: guild["name"].as_str().unwrap_or_default().to_string(),
+        total_members,
+        online_members,
+        new_members_7d,
+        new_members_30d,
+        daily_joins,
+    })
+}
+
+// ==========================================
+// Cal.com API Functions
+// ==========================================
+
+const CAL_PAST_DAYS: i64 = 30;
+const CAL_FUTURE_DAYS: i64 = 60; // Matches the Google Calendar range
+const CAL_MAX_PAGES: usize = 10;
+
+// Returns booking metrics plus the non-cancelled bookings as calendar events
+async fn fetch_cal_bookings(
+    client: &reqwest::Client,
+    api_key: &str,
+    base_url: Option<&str>, // Self-hosted API, e.g. "https://cal-api.example.com"
+) -> Result<(BookingMetrics, Vec<serde_json::Value>), String> {
+    let api_base = format!("{}/v2", base_url.unwrap_or("https://api.cal.com").trim_end_matches('/'));
+    let now = chrono::Utc::now();
+    let after_start = (now - chrono::Duration::days(CAL_PAST_DAYS)).to_rfc3339();
+    let before_end = (now + chrono::Duration::days(CAL_FUTURE_DAYS)).to_rfc3339();
+
+    let mut bookings: Vec<serde_json::Value> = Vec::new();
+    for page in 0..CAL_MAX_PAGES {
+        let skip = (page * 100).to_string();
+        let data = get_json(
+            client
+                .get(format!("{}/bookings", api_base))
+                .bearer_auth(api_key)
+                .header("cal-api-version", "2024-08-13")
+                .query(&[
+                    ("afterStart", after_start.as_str()),
+                    ("beforeEnd", before_end.as_str()),
+                    ("take", "100"),
+                    ("skip", skip.as_str()),
+                ]),
+            "Cal.com",
+        ).await?;
+
+        bookings.extend(data["data"].as_array().cloned().unwrap_or_default());
+        if !data["pagination"]["hasNextPage"].as_bool().unwrap_or(false) {
+            break;
+        }
+    }
+
+    let mut metrics = BookingMetrics {
+        provider: "cal".to_string(),
+        upcoming_bookings: 0,
+        bookings_30d: 0,
+        cancellations_30d: 0,
+        no_shows_30d: 0,
+    };
+    let mut events = Vec::new();
+
+    for booking in &bookings {
+        let Some(start) = parse_timestamp(&booking["start"]) else { continue };
+        let status = booking["status"].as_str().unwrap_or("accepted");
+        let cancelled = matches!(status, "cancelled" | "rejected");
+
+        if start <= now {
+            metrics.bookings_30d += 1;
+            if cancelled {
+                metrics.cancellations_30d += 1;
+            } else if booking["absentHost"].as_bool().unwrap_or(false)
+                || booking["attendees"]
+                    .as_array()
+                    .is_some_and(|attendees| attendees.iter().any(|a| a["absent"].as_bool().unwrap_or(false)))
+            {
+                metrics.no_shows_30d += 1;
+            }
+        } else if !cancelled {
+            metrics.upcoming_bookings += 1;
+        }
+
+        if !cancelled {
+            events.push(serde_json::json!({
+                "id": format!("cal-{}", booking["uid"].as_str().unwrap_or_default()),
+                "title": booking["title"].as_str().unwrap_or("(No title)"),
+                "startTime": booking["start"],
+                "endTime": booking["end"],
+                "source": "cal",
+                "type": "meeting",
+                "description": booking["description"],
+                "url": booking["meetingUrl"].as_str().or(booking["location"].as_str()),
+                "calendarName": "Cal.com",
+                "calendarColor": serde_json::Value::Null
+            }));
+        }
+    }
+
+    println!(
+        "Cal.com: {} upcoming bookings, {} in the last {} days",
+        metrics.upcoming_bookings, metrics.bookings_30d, CAL_PAST_DAYS
+    );
+
+    Ok((metrics, events))
+}
+
+// ==========================================
+// Custom SQL (Postgres) Functions
+// ==========================================
+
+const SQL_QUERY_TIMEOUT_SECS: u64 = 10;
+
+async fn connect_postgres(connection_string: &str) -> Result<tokio_postgres::Client, String> {
+    let mut config: tokio_postgres::Config = connection_string
+        .parse()
+        .map_err(|e: tokio_postgres::Error| format!("Invalid connection string: {}", e))?;
+    config.connect_timeout(std::time::Duration::from_secs(SQL_QUERY_TIMEOUT_SECS));
+
+    // Hosted Postgres (Supabase, Neon) requires TLS; sslmode in the string decides whether it's used
+    let tls_config = tls_client_config()?;
+
+    let (client, connection) = config
+        .connect(tokio_postgres_rustls::MakeRustlsConnect::new(tls_config))
+        .await
+        .map_err(|e| format!("Failed to connect to Postgres: {}", e))?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            println!("Postgres connection error: {}", e);
+        }
+    });
+
+    Ok(client)
+}
+
+// Runs one query in a read-only transaction and returns each row as a JSON object
+async fn run_sql_metric_query(
+    client: &mut tokio_postgres::Client,
+    sql: &str,
+) -> Result<Vec<serde_json::Value>, String> {
+    let transaction = client
+        .build_transaction()
+        .read_only(true)
+        .start()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    transaction
+        .batch_execute(&format!("SET LOCAL statement_timeout = {}", SQL_QUERY_TIMEOUT_SECS * 1000))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Wrapping keeps it to a single SELECT and gives us column names without mapping Postgres types
+    let wrapped = format!("SELECT to_jsonb(q) FROM ({}) AS q", sql.trim().trim_end_matches(';'));
+    let rows = transaction.query(&wrapped, &[]).await.map_err(|e| e.to_string())?;
+    transaction.rollback().await.ok();
+
+    rows.iter()
+        .map(|row| row.try_get::<_, serde_json::Value>(0).map_err(|e| e.to_string()))
+        .collect()
+}
+
+// Queries must name their columns; to_jsonb keeps numeric types as JSON numbers.
+// A NULL value (e.g. sum over no rows) is reported as missing rather than an error.
+fn sql_row_number(row: &serde_json::Value) -> Result<Option<f64>, String> {
+    match row.get("value") {
+        None => Err("Query must return a column named \"value\"".to_string()),
+        Some(serde_json::Value::Null) => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .map(Some)
+            .ok_or_else(|| "The \"value\" column must be numeric".to_string()),
+    }
+}
+
+fn sql_row_date(row: &serde_json::Value) -> Result<String, String> {
+    row.get("date")
+        .ok_or_else(|| "Time series queries must return a column named \"date\"".to_string())?
+        .as_str()
+        .map(normalize_analytics_date)
+        .ok_or_else(|| "The \"date\" column must be a date or timestamp".to_string())
+}
+
+// Latest value plus the oldest-first series for a query's rows
+fn sql_metric_values(
+    kind: SqlResultKind,
+    rows: &[serde_json::Value],
+) -> Result<(Option<f64>, Vec<CustomMetricPoint>), String> {
+    match kind {
+        SqlResultKind::Scalar => {
+            let value = match rows.first() {
+                Some(row) => sql_row_number(row)?,
+                None => None,
+            };
+            Ok((value, Vec::new()))
+        }
+        SqlResultKind::Timeseries => {
+            let mut series = Vec::new();
+            for row in rows {
+                let date = sql_row_date(row)?;
+                if let Some(value) = sql_row_number(row)? {
+                    series.push(CustomMetricPoint { date, value });
+                }
+            }
+            series.sort_by(|a, b| a.date.cmp(&b.date));
+            Ok((series.last().map(|p| p.value), series))
+        }
+    }
+}
+
+async fn fetch_custom_sql_metrics(
+    connection_string: &str,
+    queries: &[SqlMetricQuery],
+) -> Result<Vec<CustomMetric>, String> {
+    let mut client = connect_postgres(connection_string).await?;
+    let mut metrics = Vec::new();
+
+    // A failing query is reported on its own metric rather than failing the whole integration
+    for query in queries {
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(SQL_QUERY_TIMEOUT_SECS + 2),
+            run_sql_metric_query(&mut client, &query.sql),
+        )
+        .await
+        .unwrap_or_else(|_| Err("Query timed out".to_string()))
+        .and_then(|rows| sql_metric_values(query.kind, &rows));
+
+        let mut metric = CustomMetric {
+            name: query.name.clone(),
+            kind: query.kind,
+            value: None,
+            series: Vec::new(),
+            error: None,
+        };
+
+        match result {
+            Ok((value, series)) => {
+                metric.value = value;
+                metric.series = series;
+            }
+            Err(e) => {
+                println!("Custom SQL metric '{}' failed: {}", query.name, e);
+                metric.error = Some(e);
+            }
+        }
+
+        metrics.push(metric);
+    }
+
+    println!("Custom SQL: ran {} queries", metrics.len());
+
+    Ok(metrics)
+}
+
+// ==========================================
+// Amplitude API Functions
+// ==========================================
+
+// Fetches a Dashboard REST API chart and pairs its xValues with the first series.
+// Responses look like { data: { xValues: ["2024-01-01", ...], series: [[12, 15, ...]] } }
+async fn fetch_amplitude_series(
+    client: &reqwest::Client,
+    base_url: &str,
+    api_key: &str,
+    secret_key: &str,
+    path: &str,
+    params: &[(&str, &str)],
+) -> Result<Vec<DailyCount>, String> {
+    let response = client
+        .get(format!("{}{}", base_url, path))
+        .query(params)
+        .basic_auth(api_key, Some(secret_key))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        println!("Amplitude error ({}): {}", path, error_text);
+        return Err(format!("Failed to fetch Amplitude metrics: {}", status));
+    }
+
+    let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+
+    let empty_vec = vec![];
+    let x_values = data["data"]["xValues"].as_array().unwrap_or(&empty_vec);
+    let series = data["data"]["series"][0].as_array().unwrap_or(&empty_vec);
+
+    Ok(x_values
+        .iter()
+        .zip(series.iter())
+        .map(|(date, value)| DailyCount {
+            date: date.as_str().unwrap_or_default().to_string(),
+            count: value.as_f64().unwrap_or(0.0) as i64,
+        })
+        .collect())
+}
+
+async fn fetch_amplitude_metrics(
+    client: &reqwest::Client,
+    api_key: &str,
+    secret_key: &str,
+    event_name: Option<&str>,
+    base_url: Option<&str>, // EU projects use https://analytics.eu.amplitude.com
+) -> Result<AmplitudeMetrics, String> {
+    let base_url = base_url
+        .unwrap_or("https://amplitude.com")
+        .trim_end_matches('/');
+
+    let now = chrono::Utc::now();
+    let start = (now - chrono::Duration::days(29)).format("%Y%m%d").to_string();
+    let end = now.format("%Y%m%d").to_string();
+
+    // Daily active users over the last 30 days (i=1 is daily, 7 weekly, 30 monthly)
+    let daily_active_users = fetch_amplitude_series(
+        client, base_url, api_key, secret_key, "/api/2/users",
+        &[("start", &start), ("end", &end), ("m", "active"), ("i", "1")],
+    ).await?;
+
+    let weekly_active = fetch_amplitude_series(
+        client, base_url, api_key, secret_key, "/api/2/users",
+        &[("start", &start), ("end", &end), ("m", "active"), ("i", "7")],
+    ).await.unwrap_or_default();
+
+    let monthly_active = fetch_amplitude_series(
+        client, base_url, api_key, secret_key, "/api/2/users",
+        &[("start", &start), ("end", &end), ("m", "active"), ("i", "30")],
+    ).await.unwrap_or_default();
+
+    let daily_new_users = fetch_amplitude_series(
+        client, base_url, api_key, secret_key, "/api/2/users",
+        &[("start", &start), ("end", &end), ("m", "new"), ("i", "1")],
+    ).await.unwrap_or_default();
+
+    let dau = daily_active_users.last().map(|d| d.count).unwrap_or(0);
+    let wau = weekly_active.last().map(|d| d.count).unwrap_or(0);
+    let mau = monthly_active.last().map(|d| d.count).unwrap_or(0);
+
+    let sum_last = |series: &[DailyCount], days: usize| -> i64 {
+        series.iter().rev().take(days).map(|d| d.count).sum()
+    };
+    let new_users_1d = sum_last(&daily_new_users, 1);
+    let new_users_7d = sum_last(&daily_new_users, 7);
+    let new_users_30d = sum_last(&daily_new_users, 30);
+
+    // Optional event segmentation for the configured key event
+    let mut event = None;
+    if let Some(name) = event_name.filter(|n| !n.is_empty()) {
+        let event_filter = serde_json::json!({ "event_type": name }).to_string();
+
+        let totals = fetch_amplitude_series(
+            client, base_url, api_key, secret_key, "/api/2/events/segmentation",
+            &[("e", &event_filter), ("start", &start), ("end", &end), ("m", "totals"), ("i", "1")],
+        ).await;
+
+        match totals {
+            Ok(daily) => {
+                let uniques = fetch_amplitude_series(
+                    client, base_url, api_key, secret_key, "/api/2/events/segmentation",
+                    &[("e", &event_filter), ("start", &start), ("end", &end), ("m", "uniques"), ("i", "30")],
+                ).await.unwrap_or_default();
+
+                event = Some(AmplitudeEventSegment {
+                    event_name: name.to_string(),
+                    total_30d: daily.iter().map(|d| d.count).sum(),
+                    unique_users_30d: uniques.last().map(|d| d.count).unwrap_or(0),
+                    daily,
+                });
+            }
+            Err(e) => println!("Amplitude segmentation for '{}' failed: {}", name, e),
+        }
+    }
+
+    println!("Amplitude: DAU {}, WAU {}, MAU {}, {} new users in 7 days", dau, wau, mau, new_users_7d);
+
+    Ok(AmplitudeMetrics {
+        dau,
+        wau,
+        mau,
+        new_users_1d,
+        new_users_7d,
+        new_users_30d,
+        daily_active_users,
+        daily_new_users,
+        event,
+    })
+}
+
+// ==========================================
+// Web Analytics Providers
+// ==========================================
+
+const WEB_ANALYTICS_DAYS: i64 = 30;
+
+// Implemented by every analytics source that can fill WebAnalyticsMetrics,
+// so the dashboard and snapshot history don't care which one is configured.
+trait WebAnalyticsProvider: Sized {
+    fn from_integration(integration: &Integration) -> Option<Self>;
+
+    async fn fetch_web_analytics(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<WebAnalyticsMetrics, String>;
+}
+
+async fn fetch_web_analytics_from<P: WebAnalyticsProvider>(
+    client: &reqwest::Client,
+    integration: &Integration,
+) -> Option<WebAnalyticsMetrics> {
+    let provider = P::from_integration(integration)?;
+    match provider.fetch_web_analytics(client).await {
+        Ok(metrics) => Some(metrics),
+        Err(e) => {
+            println!("{} web analytics error: {}", integration.integration_type, e);
+            None
+        }
+    }
+}
+
+async fn fetch_web_analytics(
+    client: &reqwest::Client,
+    integration: &Integration,
+) -> Option<WebAnalyticsMetrics> {
+    match integration.integration_type.as_str() {
+        "posthog" => fetch_web_analytics_from::<PostHogAnalytics>(client, integration).await,
+        "plausible" => fetch_web_analytics_from::<PlausibleAnalytics>(client, integration).await,
+        "google_analytics" => {
+            fetch_web_analytics_from::<GoogleAnalytics>(client, integration).await
+        }
+        "umami" => fetch_web_analytics_from::<UmamiAnalytics>(client, integration).await,
+        "fathom" => fetch_web_analytics_from::<FathomAnalytics>(client, integration).await,
+        _ => None,
+    }
+}
+
+// Providers report dates as "2024-01-01", "2024-01-01 00:00:00" or "20240101"
+fn normalize_analytics_date(raw: &str) -> String {
+    if raw.len() == 8 && raw.chars().all(|c| c.is_ascii_digit()) {
+        format!("{}-{}-{}", &raw[0..4], &raw[4..6], &raw[6..8])
+    } else {
+        raw.chars().take(10).collect()
+    }
+}
+
+// Analytics APIs are inconsistent about numbers vs numeric strings
+fn json_number(value: &serde_json::Value) -> f64 {
+    value
+        .as_f64()
+        .or_else(|| value.as_str().and_then(|s| s.parse::<f64>().ok()))
+        .unwrap_or(0.0)
+}
+
+// ==========================================
+// PostHog Web Analytics (HogQL)
+// ==========================================
+
+struct PostHogAnalytics {
+    api_key: String, // Personal API key
+    project_id: String,
+    base_url: String,
+}
+
+impl PostHogAnalytics {
+    async fn query(&self, client: &reqwest::Client, hogql: &str) -> Result<Vec<serde_json::Value>, String> {
+        let response = client
+            .post(format!("{}/api/projects/{}/query/", self.base_url, self.project_id))
+            .bearer_auth(&self.api_key)
+            .json(&serde_json::json!({
+                "query": { "kind": "HogQLQuery", "query": hogql }
+            }))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("PostHog query error: {}", error_text);
+            return Err(format!("PostHog query failed: {}", status));
+        }
+
+        let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+        Ok(data["results"].as_array().cloned().unwrap_or_default())
+    }
+}
+
+impl WebAnalyticsProvider for PostHogAnalytics {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(PostHogAnalytics {
+            api_key: integration.api_key.clone()?,
+            project_id: integration.project_id.clone()?,
+            base_url: integration
+                .base_url
+                .as_deref()
+                .unwrap_or("https://us.posthog.com")
+                .trim_end_matches('/')
+                .to_string(),
+        })
+    }
+
+    async fn fetch_web_analytics(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<WebAnalyticsMetrics, String> {
+        let window = format!(
+            "event = '$pageview' AND timestamp > now() - INTERVAL {} DAY",
+            WEB_ANALYTICS_DAYS
+        );
+
+        let totals = self.query(client, &format!(
+            "SELECT count(DISTINCT person_id), count(), count(DISTINCT properties.$session_id) FROM events WHERE {}",
+            window
+        )).await?;
+        let totals = totals.first().cloned().unwrap_or_default();
+
+        // A bounce is a session with a single pageview
+        let bounces = self.query(client, &format!(
+            "SELECT countIf(views = 1), count() FROM (SELECT properties.$session_id AS session, count() AS views FROM events WHERE {} GROUP BY session)",
+            window
+        )).await.unwrap_or_default();
+        let bounces = bounces.first().cloned().unwrap_or_default();
+        let bounced_sessions = json_number(&bounces[0]);
+        let total_sessions = json_number(&bounces[1]);
+
+        let ranking = |rows: Vec<serde_json::Value>| -> Vec<RankedItem> {
+            rows.iter()
+                .map(|row| RankedItem {
+                    name: row[0].as_str().filter(|s| !s.is_empty()).unwrap_or("(direct)").to_string(),
+                    count: json_number(&row[1]) as i64,
+                })
+                .collect()
+        };
+
+        let top_pages = ranking(self.query(client, &format!(
+            "SELECT properties.$pathname AS page, count() AS views FROM events WHERE {} GROUP BY page ORDER BY views DESC LIMIT 10",
+            window
+        )).await.unwrap_or_default());
+
+        let top_sources = ranking(self.query(client, &format!(
+            "SELECT properties.$referring_domain AS source, count(DISTINCT properties.$session_id) AS sessions FROM events WHERE {} GROUP BY source ORDER BY sessions DESC LIMIT 10",
+            window
+        )).await.unwrap_or_default());
+
+        let daily = self.query(client, &format!(
+            "SELECT toDate(timestamp) AS day, count(DISTINCT person_id), count() FROM events WHERE {} GROUP BY day ORDER BY day",
+            window
+        )).await.unwrap_or_default()
+            .iter()
+            .map(|row| WebAnalyticsDay {
+                date: normalize_analytics_date(row[0].as_str().unwrap_or_default()),
+                visitors: json_number(&row[1]) as i64,
+                pageviews: json_number(&row[2]) as i64,
+            })
+            .collect();
+
+        Ok(WebAnalyticsMetrics {
+            provider: "posthog".to_string(),
+            visitors: json_number(&totals[0]) as i64,
+            pageviews: json_number(&totals[1]) as i64,
+            sessions: json_number(&totals[2]) as i64,
+            bounce_rate: if total_sessions > 0.0 {
+                (bounced_sessions / total_sessions) * 100.0
+            } else {
+                0.0
+            },
+            top_pages,
+            top_sources,
+            daily,
+        })
+    }
+}
+
+// ==========================================
+// Plausible API Functions
+// ==========================================
+
+struct PlausibleAnalytics {
+    api_key: String,
+    site_id: String, // The site's domain, e.g. "example.com"
+    base_url: String,
+}
+
+impl PlausibleAnalytics {
+    async fn get(
+        &self,
+        client: &reqwest::Client,
+        endpoint: &str,
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value, String> {
+        let period = format!("{}d", WEB_ANALYTICS_DAYS);
+        let response = client
+            .get(format!("{}/api/v1/stats/{}", self.base_url, endpoint))
+            .bearer_auth(&self.api_key)
+            .query(&[("site_id", self.site_id.as_str()), ("period", period.as_str())])
+            .query(params)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("Plausible error ({}): {}", endpoint, error_text);
+            return Err(format!("Failed to fetch Plausible {}: {}", endpoint, status));
+        }
+
+        response.json().await.map_err(|e| e.to_string())
+    }
+
+    async fn breakdown(
+        &self,
+        client: &reqwest::Client,
+        property: &str,
+        key: &str,
+        metric: &str,
+    ) -> Vec<RankedItem> {
+        let data = self
+            .get(client, "breakdown", &[("property", property), ("metrics", metric), ("limit", "10")])
+            .await
+            .unwrap_or_default();
+
+        data["results"]
+            .as_array()
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| RankedItem {
+                        name: row[key].as_str().unwrap_or("(direct)").to_string(),
+                        count: json_number(&row[metric]) as i64,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl WebAnalyticsProvider for PlausibleAnalytics {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(PlausibleAnalytics {
+            api_key: integration.api_key.clone()?,
+            site_id: integration.project_id.clone()?,
+            base_url: integration
+                .base_url
+                .as_deref()
+                .unwrap_or("https://plausible.io")
+                .trim_end_matches('/')
+                .to_string(),
+        })
+    }
+
+    async fn fetch_web_analytics(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<WebAnalyticsMetrics, String> {
+        // { results: { visitors: { value: 123 }, pageviews: { value: 456 }, ... } }
+        let aggregate = self
+            .get(client, "aggregate", &[("metrics", "visitors,pageviews,visits,bounce_rate")])
+            .await?;
+        let results = &aggregate["results"];
+
+        let top_pages = self.breakdown(client, "event:page", "page", "pageviews").await;
+        let top_sources = self.breakdown(client, "visit:source", "source", "visitors").await;
+
+        let timeseries = self
+            .get(client, "timeseries", &[("metrics", "visitors,pageviews")])
+            .await
+            .unwrap_or_default();
+        let daily = timeseries["results"]
+            .as_array()
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| WebAnalyticsDay {
+                        date: normalize_analytics_date(row["date"].as_str().unwrap_or_default()),
+                        visitors: json_number(&row["visitors"]) as i64,
+                        pageviews: json_number(&row["pageviews"]) as i64,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(WebAnalyticsMetrics {
+            provider: "plausible".to_string(),
+            visitors: json_number(&results["visitors"]["value"]) as i64,
+            pageviews: json_number(&results["pageviews"]["value"]) as i64,
+            sessions: json_number(&results["visits"]["value"]) as i64,
+            bounce_rate: json_number(&results["bounce_rate"]["value"]),
+            top_pages,
+            top_sources,
+            daily,
+        })
+    }
+}
+
+// ==========================================
+// Google Analytics (GA4) API Functions
+// ==========================================
+
+struct GoogleAnalytics {
+    access_token: String,          // Google OAuth access token with analytics.readonly
+    refresh_token: Option<String>, // Used to retry once when the access token has expired
+    property_id: String,           // Numeric GA4 property ID
+}
+
+impl GoogleAnalytics {
+    async fn run_report(
+        &self,
+        client: &reqwest::Client,
+        body: serde_json::Value,
+    ) -> Result<Vec<serde_json::Value>, String> {
+        let response = client
+            .post(format!(
+                "https://analyticsdata.googleapis.com/v1beta/properties/{}:runReport",
+                self.property_id
+            ))
+            .bearer_auth(&self.access_token)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if status.as_u16() == 401 {
+            return Err("TOKEN_EXPIRED".to_string());
+        }
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("Google Analytics error: {}", error_text);
+            return Err(format!("Failed to fetch Google Analytics report: {}", status));
+        }
+
+        let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+        Ok(data["rows"].as_array().cloned().unwrap_or_default())
+    }
+
+    async fn ranking(&self, client: &reqwest::Client, dimension: &str, metric: &str) -> Vec<RankedItem> {
+        let rows = self.run_report(client, serde_json::json!({
+            "dateRanges": [{ "startDate": format!("{}daysAgo", WEB_ANALYTICS_DAYS), "endDate": "today" }],
+            "dimensions": [{ "name": dimension }],
+            "metrics": [{ "name": metric }],
+            "orderBys": [{ "metric": { "metricName": metric }, "desc": true }],
+            "limit": 10
+        })).await.unwrap_or_default();
+
+        rows.iter()
+            .map(|row| RankedItem {
+                name: row["dimensionValues"][0]["value"].as_str().unwrap_or("(not set)").to_string(),
+                count: json_number(&row["metricValues"][0]["value"]) as i64,
+            })
+            .collect()
+    }
+}
+
+impl WebAnalyticsProvider for GoogleAnalytics {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(GoogleAnalytics {
+            access_token: integration.api_key.clone()?,
+            refresh_token: integration.api_secret.clone(),
+            property_id: integration.project_id.clone()?,
+        })
+    }
+
+    async fn fetch_web_analytics(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<WebAnalyticsMetrics, String> {
+        self.fetch_with_refresh(client).await.map(|(metrics, _)| metrics)
+    }
+}
+
+impl GoogleAnalytics {
+    // Retries once with a refreshed access token, which is returned so it can be stored
+    async fn fetch_with_refresh(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<(WebAnalyticsMetrics, Option<String>), String> {
+        match self.fetch_reports(client).await {
+            Err(e) if e == "TOKEN_EXPIRED" => {
+                let Some(refresh_token) = &self.refresh_token else {
+                    return Err(e);
+                };
+                let tokens: serde_json::Value = serde_json::from_str(
+                    &refresh_google_token(refresh_token.clone()).await?,
+                ).map_err(|e| e.to_string())?;
+                let refreshed = GoogleAnalytics {
+                    access_token: tokens["access_token"]
+                        .as_str()
+                        .ok_or_else(|| "Google token refresh returned no access token".to_string())?
+                        .to_string(),
+                    refresh_token: None,
+                    property_id: self.property_id.clone(),
+                };
+                let metrics = refreshed.fetch_reports(client).await?;
+                Ok((metrics, Some(refreshed.access_token)))
+            }
+            result => result.map(|metrics| (metrics, None)),
+        }
+    }
+
+    async fn fetch_reports(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<WebAnalyticsMetrics, String> {
+        let date_range = serde_json::json!([{
+            "startDate": format!("{}daysAgo", WEB_ANALYTICS_DAYS),
+            "endDate": "today"
+        }]);
+
+        let totals = self.run_report(client, serde_json::json!({
+            "dateRanges": date_range,
+            "metrics": [
+                { "name": "totalUsers" },
+                { "name": "screenPageViews" },
+                { "name": "sessions" },
+                { "name": "bounceRate" }
+            ]
+        })).await?;
+        let values = totals.first().map(|row| row["metricValues"].clone()).unwrap_or_default();
+
+        let top_pages = self.ranking(client, "pagePath", "screenPageViews").await;
+        let top_sources = self.ranking(client, "sessionSource", "sessions").await;
+
+        let daily = self.run_report(client, serde_json::json!({
+            "dateRanges": date_range,
+            "dimensions": [{ "name": "date" }],
+            "metrics": [{ "name": "totalUsers" }, { "name": "screenPageViews" }],
+            "orderBys": [{ "dimension": { "dimensionName": "date" } }]
+        })).await.unwrap_or_default()
+            .iter()
+            .map(|row| WebAnalyticsDay {
+                date: normalize_analytics_date(row["dimensionValues"][0]["value"].as_str().unwrap_or_default()),
+                visitors: json_number(&row["metricValues"][0]["value"]) as i64,
+                pageviews: json_number(&row["metricValues"][1]["value"]) as i64,
+            })
+            .collect();
+
+        Ok(WebAnalyticsMetrics {
+            provider: "google_analytics".to_string(),
+            visitors: json_number(&values[0]["value"]) as i64,
+            pageviews: json_number(&values[1]["value"]) as i64,
+            sessions: json_number(&values[2]["value"]) as i64,
+            bounce_rate: json_number(&values[3]["value"]) * 100.0, // GA4 reports a 0-1 ratio
+            top_pages,
+            top_sources,
+            daily,
+        })
+    }
+}
+
+// ==========================================
+// Umami API Functions
+// ==========================================
+
+struct UmamiAnalytics {
+    api_key: String,          // Umami Cloud API key, self-hosted token, or username
+    password: Option<String>, // Self-hosted password (logs in for a token)
+    website_id: String,
+    base_url: Option<String>, // Self-hosted instance, e.g. https://analytics.example.com
+}
+
+// Umami v2 wraps stats as { value, prev }, newer releases return plain numbers
+fn umami_value(value: &serde_json::Value) -> i64 {
+    value["value"].as_i64().or_else(|| value.as_i64()).unwrap_or(0)
+}
+
+impl UmamiAnalytics {
+    // Umami Cloud uses an API key header, self-hosted instances use a bearer token
+    async fn authenticate(&self, client: &reqwest::Client) -> Result<(String, (&'static str, String)), String> {
+        let Some(base) = &self.base_url else {
+            return Ok((
+                "https://api.umami.is/v1".to_string(),
+                ("x-umami-api-key", self.api_key.clone()),
+            ));
+        };
+
+        let api_base = format!("{}/api", base.trim_end_matches('/'));
+        let token = match &self.password {
+            Some(password) => {
+                let login: serde_json::Value = client
+                    .post(format!("{}/auth/login", api_base))
+                    .json(&serde_json::json!({ "username": self.api_key, "password": password }))
+                    .send()
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .json()
+                    .await
+                    .map_err(|e| e.to_string())?;
+                login["token"]
+                    .as_str()
+                    .ok_or_else(|| "Umami login failed".to_string())?
+                    .to_string()
+            }
+            None => self.api_key.clone(),
+        };
+
+        Ok((api_base, ("Authorization", format!("Bearer {}", token))))
+    }
+}
+
+async fn fetch_umami_ranking(
+    client: &reqwest::Client,
+    url: &str,
+    auth: &(&str, String),
+    metric_type: &str,
+    start_at: &str,
+    end_at: &str,
+) -> Vec<RankedItem> {
+    let response = client
+        .get(url)
+        .header(auth.0, &auth.1)
+        .query(&[("type", metric_type), ("startAt", start_at), ("endAt", end_at), ("limit", "10")])
+        .send()
+        .await;
+
+    let data: serde_json::Value = match response {
+        Ok(resp) if resp.status().is_success() => resp.json().await.unwrap_or_default(),
+        _ => return Vec::new(),
+    };
+
+    // Rows look like [{ x: "/pricing", y: 42 }, ...]
+    data.as_array()
+        .map(|rows| {
+            rows.iter()
+                .take(10)
+                .map(|row| RankedItem {
+                    name: row["x"].as_str().unwrap_or("(direct)").to_string(),
+                    count: row["y"].as_i64().unwrap_or(0),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl WebAnalyticsProvider for UmamiAnalytics {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(UmamiAnalytics {
+            api_key: integration.api_key.clone()?,
+            password: integration.api_secret.clone(),
+            website_id: integration.project_id.clone()?,
+            base_url: integration.base_url.clone(),
+        })
+    }
+
+    async fn fetch_web_analytics(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<WebAnalyticsMetrics, String> {
+        let (api_base, auth) = self.authenticate(client).await?;
+
+        let now = chrono::Utc::now();
+        let start_at = (now - chrono::Duration::days(WEB_ANALYTICS_DAYS)).timestamp_millis().to_string();
+        let end_at = now.timestamp_millis().to_string();
+
+        let stats_response = client
+            .get(format!("{}/websites/{}/stats", api_base, self.website_id))
+            .header(auth.0, &auth.1)
+            .query(&[("startAt", &start_at), ("endAt", &end_at)])
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = stats_response.status();
+        if !status.is_success() {
+            let error_text = stats_response.text().await.unwrap_or_default();
+            println!("Umami error: {}", error_text);
+            return Err(format!("Failed to fetch Umami metrics: {}", status));
+        }
+
+        let stats: serde_json::Value = stats_response.json().await.map_err(|e| e.to_string())?;
+
+        let metrics_url = format!("{}/websites/{}/metrics", api_base, self.website_id);
+        let top_pages = fetch_umami_ranking(client, &metrics_url, &auth, "url", &start_at, &end_at).await;
+        let top_sources =
+            fetch_umami_ranking(client, &metrics_url, &auth, "referrer", &start_at, &end_at).await;
+
+        // { pageviews: [{ x: "2024-01-01 00:00:00", y: 12 }], sessions: [{ x, y }] }
+        let series: serde_json::Value = match client
+            .get(format!("{}/websites/{}/pageviews", api_base, self.website_id))
+            .header(auth.0, &auth.1)
+            .query(&[("startAt", start_at.as_str()), ("endAt", end_at.as_str()), ("unit", "day"), ("timezone", "UTC")])
+            .send()
+            .await
+        {
+            Ok(resp) if resp.status().is_success() => resp.json().await.unwrap_or_default(),
+            _ => serde_json::Value::Null,
+        };
+
+        let mut days: std::collections::BTreeMap<String, (i64, i64)> = std::collections::BTreeMap::new();
+        for point in series["sessions"].as_array().into_iter().flatten() {
+            let date = normalize_analytics_date(point["x"].as_str().unwrap_or_default());
+            days.entry(date).or_default().0 += point["y"].as_i64().unwrap_or(0);
+        }
+        for point in series["pageviews"].as_array().into_iter().flatten() {
+            let date = normalize_analytics_date(point["x"].as_str().unwrap_or_default());
+            days.entry(date).or_default().1 += point["y"].as_i64().unwrap_or(0);
+        }
+        let daily = days
+            .into_iter()
+            .map(|(date, (visitors, pageviews))| WebAnalyticsDay { date, visitors, pageviews })
+            .collect();
+
+        let visits = umami_value(&stats["visits"]);
+        let bounces = umami_value(&stats["bounces"]);
+
+        Ok(WebAnalyticsMetrics {
+            provider: "umami".to_string(),
+            visitors: umami_value(&stats["visitors"]),
+            pageviews: umami_value(&stats["pageviews"]),
+            sessions: visits,
+            bounce_rate: if visits > 0 {
+                (bounces as f64 / visits as f64) * 100.0
+            } else {
+                0.0
+            },
+            top_pages,
+            top_sources,
+            daily,
+        })
+    }
+}
+
+// ==========================================
+// Fathom API Functions
+// ==========================================
+
+struct FathomAnalytics {
+    api_key: String,
+    site_id: String,
+}
+
+impl FathomAnalytics {
+    async fn aggregate(
+        &self,
+        client: &reqwest::Client,
+        params: &[(&str, &str)],
+    ) -> Result<Vec<serde_json::Value>, String> {
+        let now = chrono::Utc::now();
+        let date_from = (now - chrono::Duration::days(WEB_ANALYTICS_DAYS))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let date_to = now.format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let response = client
+            .get("https://api.usefathom.com/v1/aggregations")
+            .bearer_auth(&self.api_key)
+            .query(&[
+                ("entity", "pageview"),
+                ("entity_id", self.site_id.as_str()),
+                ("date_from", date_from.as_str()),
+                ("date_to", date_to.as_str()),
+            ])
+            .query(params)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("Fathom error: {}", error_text);
+            return Err(format!("Failed to fetch Fathom metrics: {}", status));
+        }
+
+        let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+        Ok(data.as_array().cloned().unwrap_or_default())
+    }
+
+    async fn ranking(&self, client: &reqwest::Client, field: &str) -> Vec<RankedItem> {
+        let rows = self
+            .aggregate(client, &[
+                ("aggregates", "pageviews"),
+                ("field_grouping", field),
+                ("sort_by", "pageviews:desc"),
+                ("limit", "10"),
+            ])
+            .await
+            .unwrap_or_default();
+
+        rows.iter()
+            .map(|row| RankedItem {
+                name: row[field]
+                    .as_str()
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or("(direct)")
+                    .to_string(),
+                count: json_number(&row["pageviews"]) as i64,
+            })
+            .collect()
+    }
+}
+
+impl WebAnalyticsProvider for FathomAnalytics {
+    fn from_integration(integration: &Integration) -> Option<Self> {
+        Some(FathomAnalytics {
+            api_key: integration.api_key.clone()?,
+            site_id: integration.project_id.clone()?,
+        })
+    }
+
+    async fn fetch_web_analytics(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<WebAnalyticsMetrics, String> {
+        // Fathom returns aggregate values as strings, e.g. [{ "uniques": "123", ... }]
+        let totals = self
+            .aggregate(client, &[("aggregates", "visits,uniques,pageviews,bounce_rate")])
+            .await?;
+        let summary = totals.first().cloned().unwrap_or_default();
+
+        let top_pages = self.ranking(client, "pathname").await;
+        let top_sources = self.ranking(client, "referrer_hostname").await;
+
+        let daily = self
+            .aggregate(client, &[
+                ("aggregates", "uniques,pageviews"),
+                ("date_grouping", "day"),
+                ("sort_by", "timestamp:asc"),
+            ])
+            .await
+            .unwrap_or_default()
+            .iter()
+            .map(|row| WebAnalyticsDay {
+                date: normalize_analytics_date(row["date"].as_str().unwrap_or_default()),
+                visitors: json_number(&row["uniques"]) as i64,
+                pageviews: json_number(&row["pageviews"]) as i64,
+            })
+            .collect();
+
+        Ok(WebAnalyticsMetrics {
+            provider: "fathom".to_string(),
+            visitors: json_number(&summary["uniques"]) as i64,
+            pageviews: json_number(&summary["pageviews"]) as i64,
+            sessions: json_number(&summary["visits"]) as i64,
+            bounce_rate: json_number(&summary["bounce_rate"]) * 100.0, // Fathom reports a 0-1 ratio
+            top_pages,
+            top_sources,
+            daily,
+        })
+    }
+}
+
+// ==========================================
+// Notifications (digests & alerts)
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+enum NotificationLevel {
+    #[default]
+    Info,
+    Warning,
+    Critical,
+}
+
+impl NotificationLevel {
+    fn emoji(self) -> &'static str {
+        match self {
+            NotificationLevel::Info => "📊",
+            NotificationLevel::Warning => "⚠️",
+            NotificationLevel::Critical => "🚨",
+        }
+    }
+
+    // Embed sidebar colour (Discord)
+    fn color(self) -> u32 {
+        match self {
+            NotificationLevel::Info => 0x3b82f6,
+            NotificationLevel::Warning => 0xf59e0b,
+            NotificationLevel::Critical => 0xef4444,
+        }
+    }
+}
+
+// Channel-agnostic message; each channel renders it in its own format
+#[derive(Debug)]
+struct Notification {
+    title: String,
+    body: Option<String>,
+    level: NotificationLevel,
+    fields: Vec<(String, String)>, // Label, value
+}
+
+// Integrations that Pulse can post to
+fn can_notify(integration: &Integration) -> bool {
+    match integration.integration_type.as_str() {
+        "slack" => true,
+        // Discord is also a metrics source; only notify when a webhook is set
+        "discord" => integration.api_secret.as_deref().is_some_and(|s| !s.trim().is_empty()),
+        _ => false,
+    }
+}
+
+fn notification_integrations(app: &App) -> impl Iterator<Item = &Integration> {
+    app.integrations.iter().filter(|i| i.enabled && can_notify(i))
+}
+
+async fn deliver_notification(
+    client: &reqwest::Client,
+    integration: &Integration,
+    notification: &Notification,
+) -> Result<(), String> {
+    match integration.integration_type.as_str() {
+        "slack" => post_slack_message(client, integration, notification).await,
+        "discord" => post_discord_message(client, integration, notification).await,
+        other => Err(format!("{} does not support notifications", other)),
+    }
+}
+
+// Sends to every notification channel on the app; returns how many channels
+// received it and an error per channel that didn't
+async fn broadcast_notification(app: &App, notification: &Notification) -> (usize, Vec<String>) {
+    let client = reqwest::Client::new();
+    let mut delivered = 0;
+    let mut errors = Vec::new();
+
+    for integration in notification_integrations(app) {
+        match deliver_notification(&client, integration, notification).await {
+            Ok(()) => delivered += 1,
+            Err(e) => errors.push(format!("{}: {}", integration.integration_type, e)),
+        }
+    }
+
+    (delivered, errors)
+}
+
+fn build_digest(app_name: &str, metrics: &AppMetrics) -> Notification {
+    let mut fields = Vec::new();
+    let mut level = NotificationLevel::Info;
+
+    if let Some(stripe) = &metrics.stripe {
+        fields.push(("MRR".to_string(), format!("${:.2} ({:+.2} net new)", stripe.mrr, stripe.net_new_mrr)));
+        fields.push(("New subscribers (30d)".to_string(), stripe.new_subscribers_30d.to_string()));
+        fields.push(("Churn rate".to_string(), format!("{:.1}%", stripe.churn_rate)));
+    }
+
+    if let Some(timeline) = &metrics.deploy_timeline {
+        let day_ago = chrono::Utc::now() - chrono::Duration::days(1);
+        let failed_24h = timeline
+            .deployments
+            .iter()
+            .filter(|d| d.state == DeploymentState::Error)
+            .filter(|d| {
+                chrono::DateTime::parse_from_rfc3339(&d.created_at).is_ok_and(|dt| dt > day_ago)
+            })
+            .count();
+        if failed_24h > 0 {
+            level = NotificationLevel::Warning;
+        }
+        fields.push(("Failed deploys (24h)".to_string(), failed_24h.to_string()));
+    }
+
+    for users in metrics.users.iter().flatten() {
+        fields.push((format!("New {} users (24h)", users.provider), users.new_users_1d.to_string()));
+    }
+
+    if let Some(errors) = &metrics.sentry {
+        fields.push(("Error events (24h)".to_string(), errors.events_24h.to_string()));
+    }
+
+    Notification {
+        title: format!("{} daily digest", app_name),
+        body: fields.is_empty().then(|| "No metrics available yet.".to_string()),
+        level,
+        fields,
+    }
+}
+
+// Last digest date per app, so the digest goes out once a day however often metrics refresh
+fn get_digest_state_path() -> PathBuf {
+    get_pulse_dir().join("digests.json")
+}
+
+#[tauri::command]
+async fn send_digest(app: String, metrics: String, force: bool) -> Result<bool, String> {
+    let app: App = serde_json::from_str(&app).map_err(|e| e.to_string())?;
+    let metrics: AppMetrics = serde_json::from_str(&metrics).map_err(|e| e.to_string())?;
+
+    if notification_integrations(&app).next().is_none() {
+        return Ok(false);
+    }
+
+    ensure_settings_dir();
+    let state_path = get_digest_state_path();
+    let mut last_sent: HashMap<String, String> = fs::read_to_string(&state_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    if !force && last_sent.get(&app.id) == Some(&today) {
+        return Ok(false);
+    }
+
+    let (delivered, errors) = broadcast_notification(&app, &build_digest(&app.name, &metrics)).await;
+    if delivered == 0 {
+        return Err(errors.join("; "));
+    }
+    println!("Sent {} digest to {} channel(s)", app.name, delivered);
+
+    // Once any channel has it, the digest counts as sent so the others don't get repeats
+    last_sent.insert(app.id.clone(), today);
+    let content = serde_json::to_string_pretty(&last_sent).map_err(|e| e.to_string())?;
+    fs::write(&state_path, content).map_err(|e| e.to_string())?;
+
+    if errors.is_empty() {
+        Ok(true)
+    } else {
+        Err(format!("Digest sent to {} channel(s), but failed for {}", delivered, errors.join("; ")))
+    }
+}
+
+#[tauri::command]
+async fn send_alert(
+    app: String,
+    title: String,
+    message: String,
+    level: Option<NotificationLevel>,
+) -> Result<(), String> {
+    let app: App = serde_json::from_str(&app).map_err(|e| e.to_string())?;
+
+    let notification = Notification {
+        title: format!("{}: {}", app.name, title),
+        body: Some(message),
+        level: level.unwrap_or(NotificationLevel::Warning),
+        fields: Vec::new(),
+    };
+    let (_, errors) = broadcast_notification(&app, &notification).await;
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+// Sends a fixed message through one integration so its settings can be checked before saving
+#[tauri::command]
+async fn test_notification(integration: String) -> Result<(), String> {
+    let integration: Integration = serde_json::from_str(&integration).map_err(|e| e.to_string())?;
+
+    let notification = Notification {
+        title: "Pulse test message".to_string(),
+        body: Some("If you can read this, notifications are set up correctly.".to_string()),
+        level: NotificationLevel::Info,
+        fields: Vec::new(),
+    };
+    deliver_notification(&reqwest::Client::new(), &integration, &notification).await
+}
+
+// ==========================================
+// Slack Notifications
+// ==========================================
+
+// api_key holds either an incoming webhook URL or a bot token (xoxb-...), in which
+// case project_id is the channel. base_url replaces the Slack origin, e.g.
+// "http://localhost:4000" to test against a stand-in server.
+async fn post_slack_message(
+    client: &reqwest::Client,
+    integration: &Integration,
+    notification: &Notification,
+) -> Result<(), String> {
+    let credential = integration.api_key.as_deref().ok_or("Slack webhook URL or bot token is missing")?;
+    let origin = integration.base_url.as_deref().map(|url| url.trim_end_matches('/'));
+
+    let mut blocks = vec![serde_json::json!({
+        "type": "header",
+        "text": {
+            "type": "plain_text",
+            "text": format!("{} {}", notification.level.emoji(), notification.title),
+        },
+    })];
+    if let Some(body) = &notification.body {
+        blocks.push(serde_json::json!({
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": body },
+        }));
+    }
+    // Sections hold at most 10 fields
+    for chunk in notification.fields.chunks(10) {
+        let fields: Vec<serde_json::Value> = chunk
+            .iter()
+            .map(|(label, value)| serde_json::json!({ "type": "mrkdwn", "text": format!("*{}*\n{}", label, value) }))
+            .collect();
+        blocks.push(serde_json::json!({ "type": "section", "fields": fields }));
+    }
+    blocks.push(serde_json::json!({
+        "type": "context",
+        "elements": [{
+            "type": "mrkdwn",
+            "text": format!("Sent from Pulse · {}", chrono::Utc::now().format("%b %-d, %Y %H:%M UTC")),
+        }],
+    }));
+
+    // Plain-text fallback for notifications and clients without Block Kit
+    let text = format!("{} {}", notification.level.emoji(), notification.title);
+
+    if credential.starts_with("xoxb-") {
+        let channel = integration.project_id.as_deref().ok_or("Slack channel is missing")?;
+        let response = client
+            .post(format!("{}/api/chat.postMessage", origin.unwrap_or("https://slack.com")))
+            .bearer_auth(credential)
+            .json(&serde_json::json!({ "channel": channel, "text": text, "blocks": blocks }))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // The Web API reports errors in the body with a 200 status
+        let data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+        if !data["ok"].as_bool().unwrap_or(false) {
+            let error = data["error"].as_str().unwrap_or("unknown error");
+            println!("Slack error: {}", error);
+            return Err(format!("Slack rejected the message: {}", error));
+        }
+    } else {
+        let webhook = reqwest::Url::parse(credential).map_err(|_| "Invalid Slack webhook URL".to_string())?;
+        let url = match origin {
+            Some(origin) => format!("{}{}", origin, webhook.path()),
+            None => webhook.to_string(),
+        };
+
+        let response = client
+            .post(url)
+            .json(&serde_json::json!({ "text": text, "blocks": blocks }))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            println!("Slack error: {}", error_text);
+            return Err(format!("Slack webhook failed ({}): {}", status, error_text));
+        }
+    }
+
+    Ok(())
+}
+
+// ==========================================
+// Discord Notifications
+// ==========================================
+
+// Posts an embed to the webhook in api_secret; base_url replaces the Discord origin
+async fn post_discord_message(
+    client: &reqwest::Client,
+    integration: &Integration,
+    notification: &Notification,
+) -> Result<(), String> {
+    let webhook = integration.api_secret.as_deref().ok_or("Discord webhook URL is missing")?;
+    let webhook = reqwest::Url::parse(webhook).map_err(|_| "Invalid Discord webhook URL".to_string())?;
+    let url = match integration.base_url.as_deref() {
+        Some(origin) => format!("{}{}", origin.trim_end_matches('/'), webhook.path()),
+        None => webhook.to_string(),
+    };
+
+    // Embeds hold at most 25 fields
+    let fields: Vec<serde_json::Value> = notification
+        .fields
+        .iter()
+        .take(25)
+        .map(|(label, value)| serde_json::json!({ "name": label, "value": value, "inline": true }))
+        .collect();
+
+    let mut embed = serde_json::json!({
+        "title": format!("{} {}", notification.level.emoji(), notification.title),
+        "color": notification.level.color(),
+        "fields": fields,
+        "footer": { "text": "Sent from Pulse" },
+        "timestamp": chrono::Utc::now().to_rfc3339(),
+    });
+    if let Some(body) = &notification.body {
+        embed["description"] = serde_json::json!(body);
+    }
+
+    let response = client
+        .post(url)
+        .query(&[("wait", "true")])
+        .json(&serde_json::json!({ "username": "Pulse", "embeds": [embed] }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        println!("Discord error: {}", error_text);
+        return Err(format!("Discord webhook failed ({}): {}", status, error_text));
+    }
+
+    Ok(())
+}
+
+// ==========================================
+// Google Calendar OAuth
+// ==========================================
+//
+// TODO: For production/indie release, implement "Bring Your Own Credentials" feature:
+// - Let users provide their own Google OAuth Client ID & Secret in Settings
+// - Pass credentials from frontend to these functions instead of using hardcoded values
+// - This avoids Google's app verification process and scales infinitely
+// - Current setup requires manually adding test users in Google Cloud Console
+
+// These are embedded at compile time from environment variables
+// For local dev: create .env file with GOOGLE_CLIENT_ID and GOOGLE_CLIENT_SECRET
+// For CI builds: set as GitHub Actions secrets
+const GOOGLE_CLIENT_ID: &str = env!("GOOGLE_CLIENT_ID");
+const GOOGLE_CLIENT_SECRET: &str = env!("GOOGLE_CLIENT_SECRET");
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GoogleCalendarEvent {
+    id: String,
+    summary: Option<String>,
+    start: Option<GoogleEventTime>,
+    end: Option<GoogleEventTime>,
+    description: Option<String>,
+    #[serde(rename = "htmlLink")]
+    html_link: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GoogleEventTime {
+    #[serde(rename = "dateTime")]
+    date_time: Option<String>,
+    date: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GoogleCalendarList {
+    items: Option<Vec<GoogleCalendarEvent>>,
+}
+
+// Loopback OAuth flow - opens browser, waits for callback, returns tokens
+#[tauri::command]
+async fn start_google_oauth(analytics: Option<bool>) -> Result<String, String> {
+    use tokio::net::TcpListener;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // Bind to a random available port on localhost
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .map_err(|e| format!("Failed to bind: {}", e))?;
+
+    let port = listener.local_addr()
+        .map_err(|e| format!("Failed to get local addr: {}", e))?
+        .port();
+
+    let redirect_uri = format!("http://127.0.0.1:{}", port);
+    // Request Calendar and Gmail scopes, plus Analytics (GA4 Data API) only when connecting it
+    let mut scopes = "https://www.googleapis.com/auth/calendar.readonly https://www.googleapis.com/auth/gmail.readonly".to_string();
+    if analytics.unwrap_or(false) {
+        scopes.push_str(" https://www.googleapis.com/auth/analytics.readonly");
+    }
+
+    // Build the auth URL
+    let auth_url = format!(
+        "https://accounts.google.com/o/oauth2/v2/auth?client_id={}&redirect_uri={}&response_type=code&scope={}&access_type=offline&prompt=consent",
+        GOOGLE_CLIENT_ID,
+        urlencoding::encode(&redirect_uri),
+        urlencoding::encode(&scopes)
+    );
+
+    // Open the browser
+    if let Err(e) = open::that(&auth_url) {
+        return Err(format!("Failed to open browser: {}", e));
+    }
+
+    // Wait for the OAuth callback (with 5 minute timeout)
+    let code = tokio::time::timeout(
+        std::time::Duration::from_secs(300),
+        async {
+            let (mut socket, _) = listener.accept()
+                .await
+                .map_err(|e| format!("Failed to accept connection: {}", e))?;
+
+            let mut buffer = vec![0u8; 4096];
+            let n = socket.read(&mut buffer)
+                .await
+                .map_err(|e| format!("Failed to read: {}", e))?;
+
+            let request = String::from_utf8_lossy(&buffer[..n]);
+
+            // Parse the code from the request
+            let code = request
+                .lines()
+                .next()
+                .and_then(|line| line.split_whitespace().nth(1))
+                .and_then(|path| {
+                    if path.starts_with("/?code=") {
+                        path.strip_prefix("/?code=")
+                            .and_then(|s| s.split('&').next())
+                            .map(|s| urlencoding::decode(s).unwrap_or_default().to_string())
+                    } else if path.contains("code=") {
+                        path.split("code=")
+                            .nth(1)
+                            .and_then(|s| s.split('&').next())
+                            .map(|s| urlencoding::decode(s).unwrap_or_default().to_string())
+                    } else {
+                        None
+                    }
+                })
+                .ok_or_else(|| "No authorization code found in callback".to_string())?;
+
+            // Send a success response to the browser
+            let response = "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n\
+                <html><body style=\"font-family: system-ui; text-align: center; padding: 50px;\">\
+                <h1>Authorization Successful!</h1>\
+                <p>You can close this window and return to Pulse.</p>\
+                <script>window.close();</script>\
+                </body></html>";
+
+            let _ = socket.write_all(response.as_bytes()).await;
+            let _ = socket.flush().await;
+
+            Ok::<String, String>(code)
+        }
+    )
+    .await
+    .map_err(|_| "OAuth timed out after 5 minutes".to_string())??;
+
+    // Exchange the code for tokens
+    let client = reqwest::Client::new();
+    let params = [
+        ("client_id", GOOGLE_CLIENT_ID),
+        ("client_secret", GOOGLE_CLIENT_SECRET),
+        ("code", code.as_str()),
+        ("grant_type", "authorization_code"),
+        ("redirect_uri", redirect_uri.as_str()),
+    ];
+
+    let response = client
+        .post("https://oauth2.googleapis.com/token")
+        .form(&params)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to exchange code: {}", e))?;
+
+    let status = response.status();
+    let body = response.text().await.map_err(|e| e.to_string())?;
+
+    if status.is_success() {
+        Ok(body)
+    } else {
+        Err(format!("Token exchange failed: {}", body))
+    }
+}
+
+#[tauri::command]
+async fn refresh_google_token(refresh_token: String) -> Result<String, String> {
+    let client = reqwest::Client::new();
+
+    let params = [
+        ("client_id", GOOGLE_CLIENT_ID),
+        ("client_secret", GOOGLE_CLIENT_SECRET),
+        ("refresh_token", &refresh_token),
+        ("grant_type", "refresh_token"),
+    ];
+
+    let response = client
+        .post("https://oauth2.googleapis.com/token")
+        .form(&params)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to refresh token: {}", e))?;
+
+    let status = response.status();
+    let body = response.text().await.map_err(|e| e.to_string())?;
+
+    if status.is_success() {
+        Ok(body)
+    } else {
+        Err(format!("Token refresh failed: {}", body))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GoogleCalendarListEntry {
+    id: String,
+    summary: Option<String>,
+    #[serde(rename = "backgroundColor")]
+    background_color: Option<String>,
+    #[serde(rename = "foregroundColor")]
+    foreground_color: Option<String>,
+    primary: Option<bool>,
+    selected: Option<bool>,
+    #[serde(rename = "accessRole")]
+    access_role: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GoogleCalendarListResponse {
+    items: Option<Vec<GoogleCalendarListEntry>>,
+}
+
+#[tauri::command]
+async fn fetch_google_calendar(access_token: String) -> Result<String, String> {
+    println!("Fetching Google Calendar events from ALL calendars...");
+    let client = reqwest::Client::new();
+
+    // Calculate time range: start of current month to end of next month
+    let now = chrono::Utc::now();
+    let start_of_month = now
+        .with_day(1)
+        .unwrap_or(now)
+        .with_hour(0).unwrap_or(now)
+        .with_minute(0).unwrap_or(now)
+        .with_second(0).unwrap_or(now);
+    let time_min = start_of_month.to_rfc3339();
+    // Go to end of next month (roughly 60 days to be safe)
+    let time_max = (now + chrono::Duration::days(60)).to_rfc3339();
+
+    println!("Time range: {} to {}", time_min, time_max);
+
+    // First, fetch the list of all calendars the user has access to
+    let calendar_list_url = "https://www.googleapis.com/calendar/v3/users/me/calendarList";
+    let calendar_list_response = client
+        .get(calendar_list_url)
+        .header("Authorization", format!("Bearer {}", access_token))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch calendar list: {}", e))?;
+
+    let list_status = calendar_list_response.status();
+    if list_status.as_u16() == 401 {
+        return Err("TOKEN_EXPIRED".to_string());
+    }
+
+    let calendar_list_body = calendar_list_response.text().await.map_err(|e| e.to_string())?;
+
+    if !list_status.is_success() {
+        return Err(format!("Calendar list fetch failed: {}", calendar_list_body));
+    }
+
+    // Parse the calendar list
+    let calendars: Vec<GoogleCalendarListEntry> = match serde_json::from_str::<GoogleCalendarListResponse>(&calendar_list_body) {
+        Ok(response) => response.items.unwrap_or_default(),
+        Err(e) => {
+            println!("Failed to parse calendar list: {}", e);
+            // Fall back to just primary calendar
+            vec![GoogleCalendarListEntry {
+                id: "primary".to_string(),
+                summary: Some("Primary".to_string()),
+                background_color: None,
+                foreground_color: None,
+                primary: Some(true),
+                selected: Some(true),
+                access_role: None,
+            }]
+        }
+    };
+
+    println!("Found {} calendars", calendars.len());
+    for cal in &calendars {
+        println!("  - {} ({})", cal.summary.as_deref().unwrap_or("Unknown"), cal.id);
+    }
+
+    // Fetch events from each calendar
+    let mut all_events: Vec<serde_json::Value> = Vec::new();
+
+    for calendar in &calendars {
+        // Skip calendars marked as not selected (if the field exists and is false)
+        // Most calendars don't have this field, so we include them by default
+
+        let calendar_id = urlencoding::encode(&calendar.id);
+        let url = format!(
+            "https://www.googleapis.com/calendar/v3/calendars/{}/events?timeMin={}&timeMax={}&singleEvents=true&orderBy=startTime&maxResults=250",
+            calendar_id,
+            urlencoding::encode(&time_min),
+            urlencoding::encode(&time_max)
+        );
+
+        let response = client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await;
+
+        match response {
+            Ok(resp) => {
+                let status = resp.status();
+                if status.is_success() {
+                    let body = resp.text().await.unwrap_or_default();
+                    if let Ok(calendar_data) = serde_json::from_str::<GoogleCalendarList>(&body) {
+                        let events: Vec<serde_json::Value> = calendar_data.items
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter_map(|event| {
+                                let start_time = event.start.as_ref()
+                                    .and_then(|s| s.date_time.clone().or(s.date.clone()))
+                                    .unwrap_or_default();
+                                let end_time = event.end.as_ref()
+                                    .and_then(|e| e.date_time.clone().or(e.date.clone()))
+                                    .unwrap_or_default();
+
+                                Some(serde_json::json!({
+                                    "id": event.id,
+                                    "title": event.summary.unwrap_or_else(|| "(No title)".to_string()),
+                                    "startTime": start_time,
+                                    "endTime": end_time,
+                                    "source": "google",
+                                    "type": "meeting",
+                                    "description": event.description,
+                                    "url": event.html_link,
+                                    "calendarName": calendar.summary,
+                                    "calendarColor": calendar.background_color
+                                }))
+                            })
+                            .collect();
+
+                        println!("  Calendar '{}': {} events",
+                            calendar.summary.as_deref().unwrap_or("Unknown"),
+                            events.len());
+                        all_events.extend(events);
+                    }
+                } else {
+                    println!("  Calendar '{}': failed to fetch ({})",
+                        calendar.summary.as_deref().unwrap_or("Unknown"),
+                        status);
+                }
+            }
+            Err(e) => {
+                println!("  Calendar '{}': request failed ({})",
+                    calendar.summary.as_deref().unwrap_or("Unknown"),
+                    e);
+            }
+        }
+    }
+
+    // Sort all events by start time
+    all_events.sort_by(|a, b| {
+        let a_time = a["startTime"].as_str().unwrap_or("");
+        let b_time = b["startTime"].as_str().unwrap_or("");
+        a_time.cmp(b_time)
+    });
+
+    println!("Total events from all calendars: {}", all_events.len());
+    Ok(serde_json::to_string(&all_events).unwrap_or_else(|_| "[]".to_string()))
+}
+
+// ==========================================
+// ICS Calendar Feeds
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CalendarFeed {
+    url: String, // http(s)://, webcal://, file:// or a local .ics path
+    name: String,
+    color: Option<String>, // Hex, e.g. "#0078d4"
+}
+
+// Same range as fetch_google_calendar: start of this month to 60 days out
+fn calendar_window() -> (chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>) {
+    let now = chrono::Utc::now();
+    let start_of_month = now
+        .date_naive()
+        .with_day(1)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+        .unwrap_or(now);
+    (start_of_month, now + chrono::Duration::days(60))
+}
+
+// Windows time zone names (as written by Outlook and Exchange) to IANA zones,
+// from CLDR's windowsZones.xml default ("001") territory mappings
+const WINDOWS_TIME_ZONES: &[(&str, &str)] = &[
+    ("Dateline Standard Time", "Etc/GMT+12"),
+    ("UTC-11", "Etc/GMT+11"),
+    ("Aleutian Standard Time", "America/Adak"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Marquesas Standard Time", "Pacific/Marquesas"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("UTC-09", "Etc/GMT+9"),
+    ("Pacific Standard Time (Mexico)", "America/Tijuana"),
+    ("UTC-08", "Etc/GMT+8"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Mountain Standard Time (Mexico)", "America/Mazatlan"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("Yukon Standard Time", "America/Whitehorse"),
+    ("Central America Standard Time", "America/Guatemala"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Easter Island Standard Time", "Pacific/Easter"),
+    ("Central Standard Time (Mexico)", "America/Mexico_City"),
+    ("Canada Central Standard Time", "America/Regina"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("Eastern Standard Time (Mexico)", "America/Cancun"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("Haiti Standard Time", "America/Port-au-Prince"),
+    ("Cuba Standard Time", "America/Havana"),
+    ("US Eastern Standard Time", "America/Indiana/Indianapolis"),
+    ("Turks And Caicos Standard Time", "America/Grand_Turk"),
+    ("Paraguay Standard Time", "America/Asuncion"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("Venezuela Standard Time", "America/Caracas"),
+    ("Central Brazilian Standard Time", "America/Cuiaba"),
+    ("SA Western Standard Time", "America/La_Paz"),
+    ("Pacific SA Standard Time", "America/Santiago"),
+    ("Newfoundland Standard Time", "America/St_Johns"),
+    ("Tocantins Standard Time", "America/Araguaina"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("SA Eastern Standard Time", "America/Cayenne"),
+    ("Argentina Standard Time", "America/Argentina/Buenos_Aires"),
+    ("Greenland Standard Time", "America/Nuuk"),
+    ("Montevideo Standard Time", "America/Montevideo"),
+    ("Magallanes Standard Time", "America/Punta_Arenas"),
+    ("Saint Pierre Standard Time", "America/Miquelon"),
+    ("Bahia Standard Time", "America/Bahia"),
+    ("UTC-02", "Etc/GMT+2"),
+    ("Azores Standard Time", "Atlantic/Azores"),
+    ("Cape Verde Standard Time", "Atlantic/Cape_Verde"),
+    ("UTC", "Etc/UTC"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("Sao Tome Standard Time", "Africa/Sao_Tome"),
+    ("Morocco Standard Time", "Africa/Casablanca"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Central European Standard Time", "Europe/Warsaw"),
+    ("W. Central Africa Standard Time", "Africa/Lagos"),
+    ("Jordan Standard Time", "Asia/Amman"),
+    ("GTB Standard Time", "Europe/Bucharest"),
+    ("Middle East Standard Time", "Asia/Beirut"),
+    ("Egypt Standard Time", "Africa/Cairo"),
+    ("E. Europe Standard Time", "Europe/Chisinau"),
+    ("Syria Standard Time", "Asia/Damascus"),
+    ("West Bank Standard Time", "Asia/Hebron"),
+    ("South Africa Standard Time", "Africa/Johannesburg"),
+    ("FLE Standard Time", "Europe/Kyiv"),
+    ("Israel Standard Time", "Asia/Jerusalem"),
+    ("South Sudan Standard Time", "Africa/Juba"),
+    ("Kaliningrad Standard Time", "Europe/Kaliningrad"),
+    ("Sudan Standard Time", "Africa/Khartoum"),
+    ("Libya Standard Time", "Africa/Tripoli"),
+    ("Namibia Standard Time", "Africa/Windhoek"),
+    ("Arabic Standard Time", "Asia/Baghdad"),
+    ("Turkey Standard Time", "Europe/Istanbul"),
+    ("Arab Standard Time", "Asia/Riyadh"),
+    ("Belarus Standard Time", "Europe/Minsk"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("E. Africa Standard Time", "Africa/Nairobi"),
+    ("Volgograd Standard Time", "Europe/Volgograd"),
+    ("Iran Standard Time", "Asia/Tehran"),
+    ("Arabian Standard Time", "Asia/Dubai"),
+    ("Astrakhan Standard Time", "Europe/Astrakhan"),
+    ("Azerbaijan Standard Time", "Asia/Baku"),
+    ("Russia Time Zone 3", "Europe/Samara"),
+    ("Mauritius Standard Time", "Indian/Mauritius"),
+    ("Saratov Standard Time", "Europe/Saratov"),
+    ("Georgian Standard Time", "Asia/Tbilisi"),
+    ("Caucasus Standard Time", "Asia/Yerevan"),
+    ("Afghanistan Standard Time", "Asia/Kabul"),
+    ("West Asia Standard Time", "Asia/Tashkent"),
+    ("Ekaterinburg Standard Time", "Asia/Yekaterinburg"),
+    ("Pakistan Standard Time", "Asia/Karachi"),
+    ("Qyzylorda Standard Time", "Asia/Qyzylorda"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("Sri Lanka Standard Time", "Asia/Colombo"),
+    ("Nepal Standard Time", "Asia/Kathmandu"),
+    ("Central Asia Standard Time", "Asia/Almaty"),
+    ("Bangladesh Standard Time", "Asia/Dhaka"),
+    ("Omsk Standard Time", "Asia/Omsk"),
+    ("Myanmar Standard Time", "Asia/Yangon"),
+    ("SE Asia Standard Time", "Asia/Bangkok"),
+    ("Altai Standard Time", "Asia/Barnaul"),
+    ("W. Mongolia Standard Time", "Asia/Hovd"),
+    ("North Asia Standard Time", "Asia/Krasnoyarsk"),
+    ("N. Central Asia Standard Time", "Asia/Novosibirsk"),
+    ("Tomsk Standard Time", "Asia/Tomsk"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("North Asia East Standard Time", "Asia/Irkutsk"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("W. Australia Standard Time", "Australia/Perth"),
+    ("Taipei Standard Time", "Asia/Taipei"),
+    ("Ulaanbaatar Standard Time", "Asia/Ulaanbaatar"),
+    ("Aus Central W. Standard Time", "Australia/Eucla"),
+    ("Transbaikal Standard Time", "Asia/Chita"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("North Korea Standard Time", "Asia/Pyongyang"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("Yakutsk Standard Time", "Asia/Yakutsk"),
+    ("Cen. Australia Standard Time", "Australia/Adelaide"),
+    ("AUS Central Standard Time", "Australia/Darwin"),
+    ("E. Australia Standard Time", "Australia/Brisbane"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("West Pacific Standard Time", "Pacific/Port_Moresby"),
+    ("Tasmania Standard Time", "Australia/Hobart"),
+    ("Vladivostok Standard Time", "Asia/Vladivostok"),
+    ("Lord Howe Standard Time", "Australia/Lord_Howe"),
+    ("Bougainville Standard Time", "Pacific/Bougainville"),
+    ("Russia Time Zone 10", "Asia/Srednekolymsk"),
+    ("Magadan Standard Time", "Asia/Magadan"),
+    ("Norfolk Standard Time", "Pacific/Norfolk"),
+    ("Sakhalin Standard Time", "Asia/Sakhalin"),
+    ("Central Pacific Standard Time", "Pacific/Guadalcanal"),
+    ("Russia Time Zone 11", "Asia/Kamchatka"),
+    ("New Zealand Standard Time", "Pacific/Auckland"),
+    ("UTC+12", "Etc/GMT-12"),
+    ("Fiji Standard Time", "Pacific/Fiji"),
+    ("Chatham Islands Standard Time", "Pacific/Chatham"),
+    ("UTC+13", "Etc/GMT-13"),
+    ("Tonga Standard Time", "Pacific/Tongatapu"),
+    ("Samoa Standard Time", "Pacific/Apia"),
+    ("Line Islands Standard Time", "Pacific/Kiritimati"),
+];
+
+// IANA name, or a Windows name mapped through WINDOWS_TIME_ZONES
+fn parse_tzid(tzid: &str) -> Option<chrono_tz::Tz> {
+    let tzid = tzid.trim().trim_matches('"');
+    tzid.parse::<chrono_tz::Tz>().ok().or_else(|| {
+        WINDOWS_TIME_ZONES
+            .iter()
+            .find(|(windows, _)| windows.eq_ignore_ascii_case(tzid))
+            .and_then(|(_, iana)| iana.parse().ok())
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum IcsTime {
+    Date(chrono::NaiveDate),                              // All-day
+    Utc(chrono::NaiveDateTime),                           // Trailing "Z"
+    Zoned(chrono::NaiveDateTime, Option<chrono_tz::Tz>), // TZID, or floating (system local) when None
+}
+
+impl IcsTime {
+    // "20240115", "20240115T093000Z" or "20240115T093000" (+ TZID param)
+    fn parse(value: &str, tzid: Option<&str>) -> Option<IcsTime> {
+        let value = value.trim();
+        if value.len() == 8 {
+            return chrono::NaiveDate::parse_from_str(value, "%Y%m%d").ok().map(IcsTime::Date);
+        }
+        if let Some(utc) = value.strip_suffix('Z') {
+            return chrono::NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok().map(IcsTime::Utc);
+        }
+        let local = chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+        // TZIDs that are neither IANA nor Windows names fall back to local time
+        let tz = tzid.and_then(parse_tzid);
+        Some(IcsTime::Zoned(local, tz))
+    }
+
+    fn naive(self) -> chrono::NaiveDateTime {
+        match self {
+            IcsTime::Date(date) => date.and_hms_opt(0, 0, 0).unwrap_or_default(),
+            IcsTime::Utc(dt) | IcsTime::Zoned(dt, _) => dt,
+        }
+    }
+
+    // Same kind and zone, different wall-clock time (recurrences keep local time across DST)
+    fn with_naive(self, dt: chrono::NaiveDateTime) -> IcsTime {
+        match self {
+            IcsTime::Date(_) => IcsTime::Date(dt.date()),
+            IcsTime::Utc(_) => IcsTime::Utc(dt),
+            IcsTime::Zoned(_, tz) => IcsTime::Zoned(dt, tz),
+        }
+    }
+
+    fn to_utc(self) -> chrono::DateTime<chrono::Utc> {
+        use chrono::TimeZone;
+        match self {
+            IcsTime::Utc(dt) => dt.and_utc(),
+            IcsTime::Zoned(dt, Some(tz)) => tz
+                .from_local_datetime(&dt)
+                .earliest()
+                .map(|t| t.with_timezone(&chrono::Utc))
+                .unwrap_or_else(|| dt.and_utc()),
+            IcsTime::Date(_) | IcsTime::Zoned(_, None) => chrono::Local
+                .from_local_datetime(&self.naive())
+                .earliest()
+                .map(|t| t.with_timezone(&chrono::Utc))
+                .unwrap_or_else(|| self.naive().and_utc()),
+        }
+    }
+
+    // Google's shape: "YYYY-MM-DD" for all-day events, RFC 3339 otherwise
+    fn to_event_time(self) -> String {
+        match self {
+            IcsTime::Date(date) => date.format("%Y-%m-%d").to_string(),
+            _ => self.to_utc().to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct IcsEvent {
+    uid: String,
     summary: Option<String>,
-    #[serde(rename = "backgroundColor")]
-    background_color: Option<String>,
-    #[serde(rename = "foregroundColor")]
-    foreground_color: Option<String>,
-    primary: Option<bool>,
-    selected: Option<bool>,
-    #[serde(rename = "accessRole")]
-    access_role: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    url: Option<String>,
+    start: Option<IcsTime>,
+    end: Option<IcsTime>,
+    duration: Option<chrono::Duration>,
+    rrule: Option<String>,
+    rdates: Vec<IcsTime>,
+    exdates: Vec<IcsTime>,
+    recurrence_id: Option<IcsTime>, // Set on an edited instance of a recurring event
+    cancelled: bool,
+}
+
+fn unescape_ics_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => {}
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+// "PT1H30M", "P1D", "-PT15M", "P2W"
+fn parse_ics_duration(value: &str) -> Option<chrono::Duration> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let mut total = chrono::Duration::zero();
+    let mut number = String::new();
+    for c in value.strip_prefix('P')?.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match unit {
+                    'W' => chrono::Duration::weeks(n),
+                    'D' => chrono::Duration::days(n),
+                    'H' => chrono::Duration::hours(n),
+                    'M' => chrono::Duration::minutes(n),
+                    'S' => chrono::Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+    Some(if negative { -total } else { total })
+}
+
+// Splits "DTSTART;TZID=Europe/London:20240115T093000" into name, params and value.
+// The value starts at the first colon outside a quoted parameter.
+fn parse_ics_line(line: &str) -> Option<(String, HashMap<String, String>, &str)> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ':' if !in_quotes => return Some(i),
+            _ => {}
+        }
+        None
+    })?;
+
+    let mut parts = line[..colon].split(';');
+    let name = parts.next()?.to_ascii_uppercase();
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.to_ascii_uppercase(), v.to_string()))
+        .collect();
+    Some((name, params, &line[colon + 1..]))
+}
+
+fn parse_ics(content: &str) -> Vec<IcsEvent> {
+    // Undo line folding: a line starting with a space or tab continues the previous one
+    let mut lines: Vec<String> = Vec::new();
+    for raw in content.lines() {
+        let raw = raw.trim_end_matches('\r');
+        match (raw.strip_prefix(' ').or(raw.strip_prefix('\t')), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<IcsEvent> = None;
+    let mut depth_in_event = 0; // Nested components such as VALARM
+
+    for line in &lines {
+        let Some((name, params, value)) = parse_ics_line(line) else { continue };
+        let tzid = params.get("TZID").map(|s| s.as_str());
+
+        match (name.as_str(), value.trim().to_ascii_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") if current.is_none() => {
+                current = Some(IcsEvent::default());
+                continue;
+            }
+            ("END", "VEVENT") if depth_in_event == 0 => {
+                events.extend(current.take());
+                continue;
+            }
+            ("BEGIN", _) if current.is_some() => {
+                depth_in_event += 1;
+                continue;
+            }
+            ("END", _) if current.is_some() => {
+                depth_in_event -= 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        let Some(event) = current.as_mut().filter(|_| depth_in_event == 0) else { continue };
+        match name.as_str() {
+            "UID" => event.uid = value.to_string(),
+            "SUMMARY" => event.summary = Some(unescape_ics_text(value)),
+            "DESCRIPTION" => event.description = Some(unescape_ics_text(value)),
+            "LOCATION" => event.location = Some(unescape_ics_text(value)),
+            "URL" => event.url = Some(value.to_string()),
+            "DTSTART" => event.start = IcsTime::parse(value, tzid),
+            "DTEND" => event.end = IcsTime::parse(value, tzid),
+            "DURATION" => event.duration = parse_ics_duration(value),
+            "RRULE" => event.rrule = Some(value.to_string()),
+            "RDATE" => event.rdates.extend(value.split(',').filter_map(|v| IcsTime::parse(v, tzid))),
+            "EXDATE" => event.exdates.extend(value.split(',').filter_map(|v| IcsTime::parse(v, tzid))),
+            "RECURRENCE-ID" => event.recurrence_id = IcsTime::parse(value, tzid),
+            "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            _ => {}
+        }
+    }
+
+    events
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    chrono::NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|d| d.pred_opt())
+        .map(|d| d.day())
+        .unwrap_or(28)
+}
+
+// nth (1-based, negative counts from the end) weekday of a month, or every one when n is None
+fn weekdays_in_month(year: i32, month: u32, weekday: chrono::Weekday, n: Option<i32>) -> Vec<chrono::NaiveDate> {
+    let all: Vec<chrono::NaiveDate> = (1..=days_in_month(year, month))
+        .filter_map(|day| chrono::NaiveDate::from_ymd_opt(year, month, day))
+        .filter(|d| d.weekday() == weekday)
+        .collect();
+    match n {
+        None => all,
+        Some(n) if n > 0 => all.get(n as usize - 1).copied().into_iter().collect(),
+        Some(n) => all.len().checked_sub(n.unsigned_abs() as usize).and_then(|i| all.get(i)).copied().into_iter().collect(),
+    }
+}
+
+// Days of a month matching BYMONTHDAY / BYDAY, or `default_day` when neither is set
+fn rrule_month_days(
+    year: i32,
+    month: u32,
+    by_month_day: &[i32],
+    by_day: &[(Option<i32>, chrono::Weekday)],
+    default_day: u32,
+) -> Vec<chrono::NaiveDate> {
+    let last = days_in_month(year, month) as i32;
+    let mut days: Vec<chrono::NaiveDate> = if !by_month_day.is_empty() {
+        by_month_day
+            .iter()
+            .map(|&d| if d < 0 { last + d + 1 } else { d })
+            .filter(|d| (1..=last).contains(d))
+            .filter_map(|d| chrono::NaiveDate::from_ymd_opt(year, month, d as u32))
+            .collect()
+    } else if !by_day.is_empty() {
+        by_day
+            .iter()
+            .flat_map(|&(n, weekday)| weekdays_in_month(year, month, weekday, n))
+            .collect()
+    } else {
+        // Months without the start's day (e.g. the 31st) are skipped
+        chrono::NaiveDate::from_ymd_opt(year, month, default_day).into_iter().collect()
+    };
+    days.sort();
+    days.dedup();
+    days
+}
+
+// BYSETPOS picks from each period's occurrences: 1 is the first, -1 the last
+fn select_set_positions(dates: &[chrono::NaiveDate], positions: &[i32]) -> Vec<chrono::NaiveDate> {
+    let len = dates.len() as i32;
+    let mut picked: Vec<chrono::NaiveDate> = positions
+        .iter()
+        .map(|&pos| if pos > 0 { pos - 1 } else { len + pos })
+        .filter(|index| (0..len).contains(index))
+        .map(|index| dates[index as usize])
+        .collect();
+    picked.sort();
+    picked.dedup();
+    picked
+}
+
+fn parse_ics_weekday(code: &str) -> Option<chrono::Weekday> {
+    match code {
+        "MO" => Some(chrono::Weekday::Mon),
+        "TU" => Some(chrono::Weekday::Tue),
+        "WE" => Some(chrono::Weekday::Wed),
+        "TH" => Some(chrono::Weekday::Thu),
+        "FR" => Some(chrono::Weekday::Fri),
+        "SA" => Some(chrono::Weekday::Sat),
+        "SU" => Some(chrono::Weekday::Sun),
+        _ => None,
+    }
+}
+
+// Safety net for rules that never produce a match, counted from the window
+const RRULE_MAX_PERIODS: i64 = 5000;
+
+// Rule parts that aren't implemented; rather than guess, such events show only DTSTART
+const RRULE_UNSUPPORTED_PARTS: &[&str] = &["BYWEEKNO", "BYYEARDAY", "BYHOUR", "BYMINUTE", "BYSECOND"];
+
+// Occurrence start times (in the event's wall-clock time) from around `window_start`
+// up to `window_end`. Supports FREQ, INTERVAL, COUNT, UNTIL, BYDAY, BYMONTHDAY,
+// BYMONTH, BYSETPOS and WKST.
+fn expand_rrule(
+    start: IcsTime,
+    rule: &str,
+    window_start: chrono::DateTime<chrono::Utc>,
+    window_end: chrono::DateTime<chrono::Utc>,
+) -> Vec<IcsTime> {
+    let parts: HashMap<String, String> = rule
+        .split(';')
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.to_ascii_uppercase(), v.to_ascii_uppercase()))
+        .collect();
+    if RRULE_UNSUPPORTED_PARTS.iter().any(|part| parts.contains_key(*part)) {
+        return vec![start];
+    }
+
+    let interval: i64 = parts.get("INTERVAL").and_then(|v| v.parse().ok()).filter(|&i| i > 0).unwrap_or(1);
+    let count: Option<usize> = parts.get("COUNT").and_then(|v| v.parse().ok());
+    let until = parts.get("UNTIL").and_then(|v| IcsTime::parse(v, None)).map(|t| t.to_utc());
+    let by_month: Vec<u32> = parts
+        .get("BYMONTH")
+        .map(|v| v.split(',').filter_map(|m| m.parse().ok()).collect())
+        .unwrap_or_default();
+    let by_month_day: Vec<i32> = parts
+        .get("BYMONTHDAY")
+        .map(|v| v.split(',').filter_map(|d| d.parse().ok()).collect())
+        .unwrap_or_default();
+    let by_set_pos: Vec<i32> = parts
+        .get("BYSETPOS")
+        .map(|v| v.split(',').filter_map(|p| p.parse().ok()).filter(|&p| p != 0).collect())
+        .unwrap_or_default();
+    // "MO", "2TU", "-1FR"
+    let by_day: Vec<(Option<i32>, chrono::Weekday)> = parts
+        .get("BYDAY")
+        .map(|v| {
+            v.split(',')
+                .filter_map(|d| {
+                    // The weekday code is the last two characters
+                    let (split, _) = d.char_indices().rev().nth(1)?;
+                    let (n, code) = d.split_at(split);
+                    Some((n.parse().ok(), parse_ics_weekday(code)?))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let week_start_day = parts.get("WKST").and_then(|w| parse_ics_weekday(w)).unwrap_or(chrono::Weekday::Mon);
+    let days_into_week = |weekday: chrono::Weekday| {
+        (weekday.num_days_from_monday() as i64 - week_start_day.num_days_from_monday() as i64).rem_euclid(7)
+    };
+
+    let first = start.naive();
+    let time = first.time();
+    let start_date = first.date();
+
+    // Whole periods between DTSTART and the window, less one for time zone slack,
+    // so long-running series start near the window instead of at DTSTART
+    let freq = parts.get("FREQ").map(|s| s.as_str());
+    let window_date = window_start.date_naive();
+    let periods_to_window = match freq {
+        Some("DAILY") => (window_date - start_date).num_days(),
+        Some("WEEKLY") => (window_date - start_date).num_days() / 7,
+        Some("MONTHLY") => {
+            (window_date.year() - start_date.year()) as i64 * 12 + window_date.month0() as i64
+                - start_date.month0() as i64
+        }
+        Some("YEARLY") => (window_date.year() - start_date.year()) as i64,
+        _ => return vec![start],
+    };
+    let window_period = (periods_to_window / interval - 1).max(0);
+    // COUNT includes occurrences before the window, so those periods are still walked
+    let first_period = if count.is_some() { 0 } else { window_period };
+
+    let mut occurrences = Vec::new();
+    let mut emitted = 0;
+
+    for period in first_period..window_period + RRULE_MAX_PERIODS {
+        let step = period * interval;
+        let mut dates: Vec<chrono::NaiveDate> = match freq {
+            Some("DAILY") => {
+                let date = start_date + chrono::Duration::days(step);
+                let matches_day = by_day.is_empty() || by_day.iter().any(|&(_, w)| w == date.weekday());
+                let matches_month = by_month.is_empty() || by_month.contains(&date.month());
+                let matches_month_day = by_month_day.is_empty()
+                    || rrule_month_days(date.year(), date.month(), &by_month_day, &[], date.day()).contains(&date);
+                if matches_day && matches_month && matches_month_day { vec![date] } else { vec![] }
+            }
+            Some("WEEKLY") => {
+                let week_start = start_date - chrono::Duration::days(days_into_week(start_date.weekday()))
+                    + chrono::Duration::weeks(step);
+                if by_day.is_empty() {
+                    vec![week_start + chrono::Duration::days(days_into_week(start_date.weekday()))]
+                } else {
+                    by_day
+                        .iter()
+                        .map(|&(_, w)| week_start + chrono::Duration::days(days_into_week(w)))
+                        .collect()
+                }
+            }
+            Some("MONTHLY") => {
+                let months = start_date.year() as i64 * 12 + start_date.month0() as i64 + step;
+                let (year, month) = ((months / 12) as i32, (months % 12) as u32 + 1);
+                if by_month.is_empty() || by_month.contains(&month) {
+                    rrule_month_days(year, month, &by_month_day, &by_day, start_date.day())
+                } else {
+                    vec![]
+                }
+            }
+            Some("YEARLY") => {
+                let year = start_date.year() + step as i32;
+                let months = if by_month.is_empty() { vec![start_date.month()] } else { by_month.clone() };
+                months
+                    .iter()
+                    .flat_map(|&month| rrule_month_days(year, month, &by_month_day, &by_day, start_date.day()))
+                    .collect()
+            }
+            _ => return vec![start],
+        };
+        dates.sort();
+        dates.dedup();
+        if !by_set_pos.is_empty() {
+            dates = select_set_positions(&dates, &by_set_pos);
+        }
+
+        for date in dates {
+            let occurrence = start.with_naive(date.and_time(time));
+            if occurrence.naive() < first {
+                continue;
+            }
+            let occurrence_utc = occurrence.to_utc();
+            if until.is_some_and(|until| occurrence_utc > until) || count.is_some_and(|c| emitted >= c) {
+                return occurrences;
+            }
+            if occurrence_utc > window_end {
+                return occurrences;
+            }
+            emitted += 1;
+            occurrences.push(occurrence);
+        }
+    }
+
+    occurrences
+}
+
+// Expands a parsed feed into calendar events overlapping the window
+fn ics_events_in_window(
+    events: &[IcsEvent],
+    window_start: chrono::DateTime<chrono::Utc>,
+    window_end: chrono::DateTime<chrono::Utc>,
+    source: &str,
+    calendar_name: &str,
+    calendar_color: Option<&str>,
+) -> Vec<serde_json::Value> {
+    // Edited instances replace the generated occurrence they point at
+    let overridden: std::collections::HashSet<(String, i64)> = events
+        .iter()
+        .filter_map(|e| Some((e.uid.clone(), e.recurrence_id?.to_utc().timestamp())))
+        .collect();
+
+    let mut out = Vec::new();
+    for event in events.iter().filter(|e| !e.cancelled) {
+        let Some(start) = event.start else { continue };
+        let duration = match (event.end, event.duration) {
+            (Some(end), _) => end.to_utc() - start.to_utc(),
+            (None, Some(duration)) => duration,
+            (None, None) if matches!(start, IcsTime::Date(_)) => chrono::Duration::days(1),
+            (None, None) => chrono::Duration::zero(),
+        };
+
+        // Whole days for all-day events, from the dates themselves; a UTC difference
+        // comes up an hour short across a DST change
+        let all_day_days = match (start, event.end) {
+            (IcsTime::Date(first), Some(IcsTime::Date(last))) => (last - first).num_days(),
+            _ => duration.num_days(),
+        }
+        .max(1);
+
+        // Occurrences that started before the window can still run into it
+        let expand_from = window_start - duration.max(chrono::Duration::zero());
+        let mut starts = match (&event.rrule, event.recurrence_id) {
+            (Some(rule), None) => expand_rrule(start, rule, expand_from, window_end),
+            _ => vec![start],
+        };
+        starts.extend(event.rdates.iter().copied());
+
+        let excluded: Vec<i64> = event.exdates.iter().map(|t| t.to_utc().timestamp()).collect();
+
+        for occurrence in starts {
+            let begins = occurrence.to_utc();
+            let ends = begins + duration;
+            if ends < window_start || begins > window_end || excluded.contains(&begins.timestamp()) {
+                continue;
+            }
+            if event.recurrence_id.is_none() && overridden.contains(&(event.uid.clone(), begins.timestamp())) {
+                continue;
+            }
+
+            let end_time = match occurrence {
+                IcsTime::Date(date) => (date + chrono::Duration::days(all_day_days))
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                _ => ends.to_rfc3339(),
+            };
+
+            out.push(serde_json::json!({
+                "id": format!("{}-{}-{}", source, event.uid, begins.timestamp()),
+                "title": event.summary.clone().unwrap_or_else(|| "(No title)".to_string()),
+                "startTime": occurrence.to_event_time(),
+                "endTime": end_time,
+                "source": source,
+                "type": "meeting",
+                "description": event.description,
+                "url": event.url.clone().or_else(|| event.location.clone()),
+                "calendarName": calendar_name,
+                "calendarColor": calendar_color
+            }));
+        }
+    }
+    out
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GoogleCalendarListResponse {
-    items: Option<Vec<GoogleCalendarListEntry>>,
+// Sort key for event JSON; all-day events use plain "YYYY-MM-DD" dates
+fn calendar_event_start(value: &serde_json::Value) -> Option<chrono::DateTime<chrono::Utc>> {
+    parse_timestamp(value).or_else(|| {
+        value
+            .as_str()
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|dt| dt.and_utc())
+    })
+}
+
+async fn read_calendar_feed(client: &reqwest::Client, url: &str) -> Result<String, String> {
+    let url = url.trim();
+    // webcal:// is just a hint to open the feed in a calendar app
+    let http_url = match url.strip_prefix("webcal://") {
+        Some(rest) => Some(format!("https://{}", rest)),
+        None if url.starts_with("http://") || url.starts_with("https://") => Some(url.to_string()),
+        None => None,
+    };
+    if let Some(http_url) = http_url {
+        let response = client.get(http_url).send().await.map_err(|e| e.to_string())?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("Failed to fetch calendar feed: {}", status));
+        }
+        return response.text().await.map_err(|e| e.to_string());
+    }
+
+    // Local file: "file:///path/cal.ics", "~/cal.ics" or "/path/cal.ics"
+    let path = url.strip_prefix("file://").unwrap_or(url);
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().ok_or("Could not find home directory")?.join(rest),
+        None => PathBuf::from(path),
+    };
+    fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
 }
 
 #[tauri::command]
-async fn fetch_google_calendar(access_token: String) -> Result<String, String> {
-    println!("Fetching Google Calendar events from ALL calendars...");
+async fn fetch_ics_calendars(feeds: String) -> Result<String, String> {
+    let feeds: Vec<CalendarFeed> = serde_json::from_str(&feeds).map_err(|e| e.to_string())?;
     let client = reqwest::Client::new();
+    let (window_start, window_end) = calendar_window();
 
-    // Calculate time range: start of current month to end of next month
-    let now = chrono::Utc::now();
-    let start_of_month = now
-        .with_day(1)
-        .unwrap_or(now)
-        .with_hour(0).unwrap_or(now)
-        .with_minute(0).unwrap_or(now)
-        .with_second(0).unwrap_or(now);
-    let time_min = start_of_month.to_rfc3339();
-    // Go to end of next month (roughly 60 days to be safe)
-    let time_max = (now + chrono::Duration::days(60)).to_rfc3339();
+    let mut all_events: Vec<serde_json::Value> = Vec::new();
+    for feed in &feeds {
+        match read_calendar_feed(&client, &feed.url).await {
+            Ok(content) => {
+                let events = ics_events_in_window(
+                    &parse_ics(&content),
+                    window_start,
+                    window_end,
+                    "ics",
+                    &feed.name,
+                    feed.color.as_deref(),
+                );
+                println!("  Feed '{}': {} events", feed.name, events.len());
+                all_events.extend(events);
+            }
+            Err(e) => println!("  Feed '{}': {}", feed.name, e),
+        }
+    }
 
-    println!("Time range: {} to {}", time_min, time_max);
+    all_events.sort_by_key(|e| calendar_event_start(&e["startTime"]));
 
-    // First, fetch the list of all calendars the user has access to
-    let calendar_list_url = "https://www.googleapis.com/calendar/v3/users/me/calendarList";
-    let calendar_list_response = client
-        .get(calendar_list_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch calendar list: {}", e))?;
+    Ok(serde_json::to_string(&all_events).unwrap_or_else(|_| "[]".to_string()))
+}
 
-    let list_status = calendar_list_response.status();
-    if list_status.as_u16() == 401 {
-        return Err("TOKEN_EXPIRED".to_string());
+// ==========================================
+// CalDAV Calendars
+// ==========================================
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CalDavAccount {
+    #[serde(rename = "serverUrl")]
+    server_url: String, // e.g. https://caldav.fastmail.com/ or https://cloud.example.com/remote.php/dav
+    username: String,
+    password: String, // App password
+    color: Option<String>, // Overrides each calendar's own colour
+}
+
+// One <response> of a WebDAV multistatus
+#[derive(Debug, Default)]
+struct DavResponse {
+    href: String,
+    props: HashMap<String, String>, // Local name -> text (nested <href>s included)
+    resource_types: Vec<String>,    // e.g. ["collection", "calendar"]
+    components: Vec<String>,        // supported-calendar-component-set, e.g. ["VEVENT", "VTODO"]
+}
+
+fn parse_multistatus(xml: &str) -> Result<Vec<DavResponse>, String> {
+    use quick_xml::events::Event;
+
+    let mut reader = quick_xml::Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut responses = Vec::new();
+    let mut current: Option<DavResponse> = None;
+    let mut stack: Vec<String> = Vec::new();
+
+    loop {
+        let event = reader.read_event().map_err(|e| format!("Invalid CalDAV response: {}", e))?;
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                let is_empty = matches!(event, Event::Empty(_));
+
+                if name == "response" {
+                    current = Some(DavResponse::default());
+                } else if let Some(response) = current.as_mut() {
+                    match stack.last().map(|s| s.as_str()) {
+                        Some("resourcetype") => response.resource_types.push(name.clone()),
+                        Some("supported-calendar-component-set") if name == "comp" => {
+                            if let Ok(Some(attr)) = e.try_get_attribute("name") {
+                                if let Ok(value) = attr.unescape_value() {
+                                    response.components.push(value.to_string());
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                if !is_empty {
+                    stack.push(name);
+                }
+            }
+            Event::End(_) => {
+                let closed = stack.pop();
+                if closed.as_deref() == Some("response") {
+                    responses.extend(current.take());
+                }
+            }
+            Event::Text(ref t) => {
+                let text = t.unescape().map_err(|e| e.to_string())?.to_string();
+                record_dav_text(current.as_mut(), &stack, &text);
+            }
+            Event::CData(ref c) => {
+                let text = String::from_utf8_lossy(c).to_string();
+                record_dav_text(current.as_mut(), &stack, &text);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
     }
 
-    let calendar_list_body = calendar_list_response.text().await.map_err(|e| e.to_string())?;
+    Ok(responses)
+}
 
-    if !list_status.is_success() {
-        return Err(format!("Calendar list fetch failed: {}", calendar_list_body));
+// Text belongs to the response's own <href>, or to the property element under <prop>
+fn record_dav_text(response: Option<&mut DavResponse>, stack: &[String], text: &str) {
+    let Some(response) = response else { return };
+    let Some(response_index) = stack.iter().rposition(|s| s == "response") else { return };
+    let path = &stack[response_index + 1..];
+
+    match path {
+        [href] if href == "href" => response.href = text.to_string(),
+        [propstat, prop, property, ..] if propstat == "propstat" && prop == "prop" => {
+            response.props.entry(property.clone()).or_default().push_str(text);
+        }
+        _ => {}
     }
+}
 
-    // Parse the calendar list
-    let calendars: Vec<GoogleCalendarListEntry> = match serde_json::from_str::<GoogleCalendarListResponse>(&calendar_list_body) {
-        Ok(response) => response.items.unwrap_or_default(),
-        Err(e) => {
-            println!("Failed to parse calendar list: {}", e);
-            // Fall back to just primary calendar
-            vec![GoogleCalendarListEntry {
-                id: "primary".to_string(),
-                summary: Some("Primary".to_string()),
-                background_color: None,
-                foreground_color: None,
-                primary: Some(true),
-                selected: Some(true),
-                access_role: None,
-            }]
+const CALDAV_MAX_REDIRECTS: usize = 5;
+
+// `client` must not follow redirects: reqwest replays a redirected PROPFIND or
+// REPORT as a bodiless GET, so `request` follows them itself
+struct CalDavClient<'a> {
+    client: &'a reqwest::Client,
+    account: &'a CalDavAccount,
+}
+
+impl CalDavClient<'_> {
+    // Returns the URL that finally answered, for resolving relative hrefs, and its responses
+    async fn request(
+        &self,
+        method: &[u8],
+        url: &reqwest::Url,
+        depth: &str,
+        body: String,
+    ) -> Result<(reqwest::Url, Vec<DavResponse>), String> {
+        let method = reqwest::Method::from_bytes(method).map_err(|e| e.to_string())?;
+        let mut url = url.clone();
+
+        for _ in 0..=CALDAV_MAX_REDIRECTS {
+            let response = self
+                .client
+                .request(method.clone(), url.clone())
+                .basic_auth(&self.account.username, Some(&self.account.password))
+                .header("Depth", depth)
+                .header("Content-Type", "application/xml; charset=utf-8")
+                .body(body.clone())
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let status = response.status();
+            if status.is_redirection() {
+                let location = response
+                    .headers()
+                    .get(reqwest::header::LOCATION)
+                    .and_then(|l| l.to_str().ok())
+                    .ok_or_else(|| format!("CalDAV redirect from {} has no location", url))?;
+                url = url.join(location).map_err(|e| e.to_string())?;
+                continue;
+            }
+            if status.as_u16() == 401 {
+                return Err("CalDAV login failed; check the username and app password".to_string());
+            }
+            if !status.is_success() {
+                return Err(format!("CalDAV request to {} failed: {}", url, status));
+            }
+
+            let body = response.text().await.map_err(|e| e.to_string())?;
+            return Ok((url, parse_multistatus(&body)?));
         }
-    };
 
-    println!("Found {} calendars", calendars.len());
-    for cal in &calendars {
-        println!("  - {} ({})", cal.summary.as_deref().unwrap_or("Unknown"), cal.id);
+        Err(format!("Too many CalDAV redirects, last to {}", url))
     }
 
-    // Fetch events from each calendar
-    let mut all_events: Vec<serde_json::Value> = Vec::new();
+    // Follows a single href property (e.g. current-user-principal) from `url`
+    async fn find_href(&self, url: &reqwest::Url, property: &str, xmlns: &str) -> Result<reqwest::Url, String> {
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?><d:propfind xmlns:d="DAV:" xmlns:c="{}"><d:prop><{}/></d:prop></d:propfind>"#,
+            xmlns, property
+        );
+        let (url, responses) = self.request(b"PROPFIND", url, "0", body).await?;
+        let href = responses
+            .into_iter()
+            .find_map(|r| r.props.get(property.split(':').nth(1).unwrap_or(property)).cloned())
+            .filter(|href| !href.trim().is_empty())
+            .ok_or_else(|| format!("CalDAV server did not return {}", property))?;
+        url.join(href.trim()).map_err(|e| e.to_string())
+    }
+}
 
-    for calendar in &calendars {
-        // Skip calendars marked as not selected (if the field exists and is false)
-        // Most calendars don't have this field, so we include them by default
+// Discovers every event calendar on the account and returns its events in the window
+async fn fetch_caldav_account(
+    client: &reqwest::Client,
+    account: &CalDavAccount,
+    window_start: chrono::DateTime<chrono::Utc>,
+    window_end: chrono::DateTime<chrono::Utc>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let dav = CalDavClient { client, account };
+    let server = reqwest::Url::parse(account.server_url.trim()).map_err(|_| "Invalid CalDAV server URL".to_string())?;
+
+    // Servers that only answer at their CalDAV context path advertise it at
+    // /.well-known/caldav (RFC 6764), usually as a redirect
+    let principal = match dav.find_href(&server, "d:current-user-principal", "DAV:").await {
+        Ok(principal) => principal,
+        Err(e) => {
+            let well_known = server.join("/.well-known/caldav").map_err(|e| e.to_string())?;
+            dav.find_href(&well_known, "d:current-user-principal", "DAV:")
+                .await
+                .map_err(|_| e)?
+        }
+    };
+    let home = dav
+        .find_href(&principal, "c:calendar-home-set", "urn:ietf:params:xml:ns:caldav")
+        .await?;
+
+    let (home, listing) = dav
+        .request(
+            b"PROPFIND",
+            &home,
+            "1",
+            r#"<?xml version="1.0" encoding="utf-8"?><d:propfind xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav" xmlns:a="http://apple.com/ns/ical/"><d:prop><d:resourcetype/><d:displayname/><a:calendar-color/><c:supported-calendar-component-set/></d:prop></d:propfind>"#.to_string(),
+        )
+        .await?;
+
+    // Calendars that hold events; servers that omit the component set are assumed to
+    let calendars = listing.into_iter().filter(|r| {
+        r.resource_types.iter().any(|t| t == "calendar")
+            && (r.components.is_empty() || r.components.iter().any(|c| c.eq_ignore_ascii_case("VEVENT")))
+    });
 
-        let calendar_id = urlencoding::encode(&calendar.id);
-        let url = format!(
-            "https://www.googleapis.com/calendar/v3/calendars/{}/events?timeMin={}&timeMax={}&singleEvents=true&orderBy=startTime&maxResults=250",
-            calendar_id,
-            urlencoding::encode(&time_min),
-            urlencoding::encode(&time_max)
-        );
+    let time_range = |dt: chrono::DateTime<chrono::Utc>| dt.format("%Y%m%dT%H%M%SZ").to_string();
+    let query = format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav"><d:prop><c:calendar-data/></d:prop><c:filter><c:comp-filter name="VCALENDAR"><c:comp-filter name="VEVENT"><c:time-range start="{}" end="{}"/></c:comp-filter></c:comp-filter></c:filter></c:calendar-query>"#,
+        time_range(window_start),
+        time_range(window_end)
+    );
 
-        let response = client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", access_token))
-            .send()
-            .await;
+    let mut all_events = Vec::new();
+    for calendar in calendars {
+        let url = home.join(&calendar.href).map_err(|e| e.to_string())?;
+        let name = calendar
+            .props
+            .get("displayname")
+            .filter(|n| !n.is_empty())
+            .cloned()
+            .unwrap_or_else(|| "Calendar".to_string());
+        // Apple-style colours carry alpha ("#FF2968FF"); keep "#RRGGBB"
+        let color = account
+            .color
+            .clone()
+            .or_else(|| calendar.props.get("calendar-color").map(|c| c.chars().take(7).collect()));
+
+        match dav.request(b"REPORT", &url, "1", query.clone()).await {
+            Ok((_, resources)) => {
+                // Each resource is a VCALENDAR holding one event and its edited instances
+                let events: Vec<serde_json::Value> = resources
+                    .iter()
+                    .filter_map(|r| r.props.get("calendar-data"))
+                    .flat_map(|ics| {
+                        ics_events_in_window(&parse_ics(ics), window_start, window_end, "caldav", &name, color.as_deref())
+                    })
+                    .collect();
+                println!("  CalDAV calendar '{}': {} events", name, events.len());
+                all_events.extend(events);
+            }
+            Err(e) => println!("  CalDAV calendar '{}': {}", name, e),
+        }
+    }
 
-        match response {
-            Ok(resp) => {
-                let status = resp.status();
-                if status.is_success() {
-                    let body = resp.text().await.unwrap_or_default();
-                    if let Ok(calendar_data) = serde_json::from_str::<GoogleCalendarList>(&body) {
-                        let events: Vec<serde_json::Value> = calendar_data.items
-                            .unwrap_or_default()
-                            .into_iter()
-                            .filter_map(|event| {
-                                let start_time = event.start.as_ref()
-                                    .and_then(|s| s.date_time.clone().or(s.date.clone()))
-                                    .unwrap_or_default();
-                                let end_time = event.end.as_ref()
-                                    .and_then(|e| e.date_time.clone().or(e.date.clone()))
-                                    .unwrap_or_default();
+    Ok(all_events)
+}
 
-                                Some(serde_json::json!({
-                                    "id": event.id,
-                                    "title": event.summary.unwrap_or_else(|| "(No title)".to_string()),
-                                    "startTime": start_time,
-                                    "endTime": end_time,
-                                    "source": "google",
-                                    "type": "meeting",
-                                    "description": event.description,
-                                    "url": event.html_link,
-                                    "calendarName": calendar.summary,
-                                    "calendarColor": calendar.background_color
-                                }))
-                            })
-                            .collect();
+#[tauri::command]
+async fn fetch_caldav_calendars(accounts: String) -> Result<String, String> {
+    let accounts: Vec<CalDavAccount> = serde_json::from_str(&accounts).map_err(|e| e.to_string())?;
+    // CalDavClient follows redirects itself
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| e.to_string())?;
+    let (window_start, window_end) = calendar_window();
 
-                        println!("  Calendar '{}': {} events",
-                            calendar.summary.as_deref().unwrap_or("Unknown"),
-                            events.len());
-                        all_events.extend(events);
-                    }
-                } else {
-                    println!("  Calendar '{}': failed to fetch ({})",
-                        calendar.summary.as_deref().unwrap_or("Unknown"),
-                        status);
-                }
-            }
+    let mut all_events: Vec<serde_json::Value> = Vec::new();
+    let mut errors = Vec::new();
+    for account in &accounts {
+        match fetch_caldav_account(&client, account, window_start, window_end).await {
+            Ok(events) => all_events.extend(events),
             Err(e) => {
-                println!("  Calendar '{}': request failed ({})",
-                    calendar.summary.as_deref().unwrap_or("Unknown"),
-                    e);
+                println!("CalDAV account {}: {}", account.username, e);
+                errors.push(e);
             }
         }
     }
 
-    // Sort all events by start time
-    all_events.sort_by(|a, b| {
-        let a_time = a["startTime"].as_str().unwrap_or("");
-        let b_time = b["startTime"].as_str().unwrap_or("");
-        a_time.cmp(b_time)
-    });
+    // Only fail when nothing could be fetched, so one bad account doesn't hide the rest
+    if all_events.is_empty() && !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
 
-    println!("Total events from all calendars: {}", all_events.len());
+    all_events.sort_by_key(|e| calendar_event_start(&e["startTime"]));
     Ok(serde_json::to_string(&all_events).unwrap_or_else(|_| "[]".to_string()))
 }
 
@@ -2018,9 +9349,14 @@ pub fn run() {
             get_history,
             save_snapshot,
             fetch_app_metrics,
+            send_digest,
+            send_alert,
+            test_notification,
             start_google_oauth,
             refresh_google_token,
             fetch_google_calendar,
+            fetch_ics_calendars,
+            fetch_caldav_calendars,
             fetch_gmail,
             fetch_github_metrics,
             get_auto_launch_enabled,
@@ -2029,3 +9365,459 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32) -> chrono::DateTime<chrono::Utc> {
+        chrono::NaiveDate::from_ymd_opt(y, m, d)
+            .and_then(|date| date.and_hms_opt(h, 0, 0))
+            .unwrap()
+            .and_utc()
+    }
+
+    fn dates(occurrences: &[IcsTime]) -> Vec<String> {
+        occurrences.iter().map(|t| t.naive().format("%Y-%m-%d").to_string()).collect()
+    }
+
+    fn deployment(state: DeploymentState, duration: Option<i64>) -> Deployment {
+        Deployment {
+            id: "dep".to_string(),
+            provider: "railway".to_string(),
+            name: "api".to_string(),
+            environment: "production".to_string(),
+            state,
+            created_at: "2024-01-15T12:00:00Z".to_string(),
+            started_at: None,
+            finished_at: None,
+            duration,
+            url: String::new(),
+            commit_sha: None,
+            commit_message: None,
+            commit_author: None,
+        }
+    }
+
+    #[test]
+    fn parse_ics_duration_reads_each_unit() {
+        assert_eq!(parse_ics_duration("PT1H30M"), Some(chrono::Duration::minutes(90)));
+        assert_eq!(parse_ics_duration("P1D"), Some(chrono::Duration::days(1)));
+        assert_eq!(parse_ics_duration("P1DT2H"), Some(chrono::Duration::hours(26)));
+        assert_eq!(parse_ics_duration("P2W"), Some(chrono::Duration::weeks(2)));
+        assert_eq!(parse_ics_duration("-PT15M"), Some(chrono::Duration::minutes(-15)));
+        assert_eq!(parse_ics_duration("+PT45S"), Some(chrono::Duration::seconds(45)));
+    }
+
+    #[test]
+    fn parse_ics_duration_rejects_malformed_values() {
+        assert_eq!(parse_ics_duration("1H"), None);
+        assert_eq!(parse_ics_duration("PH"), None);
+        assert_eq!(parse_ics_duration("P1X"), None);
+    }
+
+    #[test]
+    fn parse_ics_reads_events() {
+        let content = "BEGIN:VCALENDAR\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:standup@example.com\r\n\
+            SUMMARY:Daily standup\\, team\r\n\
+            DESCRIPTION:Line one\\nline \r\n two\r\n\
+            DTSTART;TZID=\"Pacific Standard Time\":20240115T093000\r\n\
+            DURATION:PT15M\r\n\
+            RRULE:FREQ=DAILY;COUNT=5\r\n\
+            EXDATE;TZID=Europe/London:20240116T093000,20240117T093000\r\n\
+            BEGIN:VALARM\r\n\
+            DESCRIPTION:Reminder\r\n\
+            END:VALARM\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:offsite@example.com\r\n\
+            DTSTART;VALUE=DATE:20240301\r\n\
+            DTEND;VALUE=DATE:20240303\r\n\
+            STATUS:CANCELLED\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+
+        let events = parse_ics(content);
+        assert_eq!(events.len(), 2);
+
+        let standup = &events[0];
+        assert_eq!(standup.uid, "standup@example.com");
+        assert_eq!(standup.summary.as_deref(), Some("Daily standup, team"));
+        assert_eq!(standup.description.as_deref(), Some("Line one\nline two"));
+        assert_eq!(standup.duration, Some(chrono::Duration::minutes(15)));
+        assert_eq!(standup.rrule.as_deref(), Some("FREQ=DAILY;COUNT=5"));
+        assert_eq!(standup.exdates.len(), 2);
+        assert!(!standup.cancelled);
+        match standup.start {
+            Some(IcsTime::Zoned(dt, Some(tz))) => {
+                assert_eq!(dt.format("%Y%m%dT%H%M%S").to_string(), "20240115T093000");
+                assert_eq!(tz, chrono_tz::America::Los_Angeles);
+            }
+            other => panic!("unexpected start {:?}", other),
+        }
+
+        let offsite = &events[1];
+        assert!(offsite.cancelled);
+        assert!(matches!(offsite.start, Some(IcsTime::Date(_))));
+        assert!(matches!(offsite.end, Some(IcsTime::Date(_))));
+    }
+
+    #[test]
+    fn windows_time_zones_map_to_iana() {
+        assert_eq!(parse_tzid("W. Europe Standard Time"), Some(chrono_tz::Europe::Berlin));
+        assert_eq!(parse_tzid("America/New_York"), Some(chrono_tz::America::New_York));
+        assert_eq!(parse_tzid("Not A Zone"), None);
+        for (windows, iana) in WINDOWS_TIME_ZONES {
+            assert!(iana.parse::<chrono_tz::Tz>().is_ok(), "{} maps to unknown zone {}", windows, iana);
+        }
+    }
+
+    #[test]
+    fn expand_rrule_weekly_by_day_with_count() {
+        let start = IcsTime::parse("20240101T090000Z", None).unwrap();
+        let occurrences = expand_rrule(start, "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4", utc(2024, 1, 1, 0), utc(2024, 3, 1, 0));
+        assert_eq!(dates(&occurrences), ["2024-01-01", "2024-01-03", "2024-01-08", "2024-01-10"]);
+    }
+
+    #[test]
+    fn expand_rrule_honours_week_start() {
+        // RFC 5545 section 3.8.5.3: WKST changes which days share a week when INTERVAL > 1
+        let start = IcsTime::parse("19970805T090000Z", None).unwrap();
+        let monday = expand_rrule(start, "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO", utc(1997, 8, 1, 0), utc(1997, 12, 1, 0));
+        assert_eq!(dates(&monday), ["1997-08-05", "1997-08-10", "1997-08-19", "1997-08-24"]);
+        let sunday = expand_rrule(start, "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU", utc(1997, 8, 1, 0), utc(1997, 12, 1, 0));
+        assert_eq!(dates(&sunday), ["1997-08-05", "1997-08-17", "1997-08-19", "1997-08-31"]);
+    }
+
+    #[test]
+    fn expand_rrule_by_set_pos_picks_last_weekday() {
+        let start = IcsTime::parse("20240131T090000Z", None).unwrap();
+        let occurrences = expand_rrule(
+            start,
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            utc(2024, 1, 1, 0),
+            utc(2024, 4, 30, 12),
+        );
+        assert_eq!(dates(&occurrences), ["2024-01-31", "2024-02-29", "2024-03-29", "2024-04-30"]);
+    }
+
+    #[test]
+    fn expand_rrule_starts_old_series_near_the_window() {
+        let start = IcsTime::parse("20000101T090000Z", None).unwrap();
+        let occurrences = expand_rrule(start, "FREQ=DAILY", utc(2024, 6, 1, 0), utc(2024, 6, 3, 12));
+        let days = dates(&occurrences);
+        assert!(days.contains(&"2024-06-01".to_string()));
+        assert_eq!(days.last().map(|s| s.as_str()), Some("2024-06-03"));
+        assert!(days.len() <= 4);
+    }
+
+    #[test]
+    fn expand_rrule_count_covers_occurrences_before_the_window() {
+        let start = IcsTime::parse("20000101T090000Z", None).unwrap();
+        let occurrences = expand_rrule(start, "FREQ=DAILY;COUNT=3", utc(2024, 6, 1, 0), utc(2024, 6, 3, 0));
+        assert_eq!(dates(&occurrences), ["2000-01-01", "2000-01-02", "2000-01-03"]);
+    }
+
+    #[test]
+    fn expand_rrule_skips_non_ascii_by_day() {
+        let start = IcsTime::parse("20240101T090000Z", None).unwrap();
+        let occurrences = expand_rrule(start, "FREQ=WEEKLY;BYDAY=MÖ,TU;COUNT=2", utc(2024, 1, 1, 0), utc(2024, 2, 1, 0));
+        assert_eq!(dates(&occurrences), ["2024-01-02", "2024-01-09"]);
+    }
+
+    #[test]
+    fn expand_rrule_unsupported_parts_keep_only_the_start() {
+        let start = IcsTime::parse("20240101T090000Z", None).unwrap();
+        let occurrences = expand_rrule(start, "FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO", utc(2024, 1, 1, 0), utc(2026, 1, 1, 0));
+        assert_eq!(dates(&occurrences), ["2024-01-01"]);
+    }
+
+    #[test]
+    fn all_day_events_keep_their_dates() {
+        let events = parse_ics(
+            "BEGIN:VEVENT\nUID:trip\nDTSTART;VALUE=DATE:20240307\nDTEND;VALUE=DATE:20240314\nEND:VEVENT\n",
+        );
+        let out = ics_events_in_window(&events, utc(2024, 3, 1, 0), utc(2024, 4, 1, 0), "ics", "Personal", None);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0]["startTime"], "2024-03-07");
+        assert_eq!(out[0]["endTime"], "2024-03-14");
+    }
+
+    #[test]
+    fn parse_multistatus_reads_nested_hrefs() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+            <d:multistatus xmlns:d="DAV:">
+              <d:response>
+                <d:href>/</d:href>
+                <d:propstat>
+                  <d:prop>
+                    <d:current-user-principal><d:href>/principals/users/jane/</d:href></d:current-user-principal>
+                  </d:prop>
+                  <d:status>HTTP/1.1 200 OK</d:status>
+                </d:propstat>
+              </d:response>
+            </d:multistatus>"#;
+
+        let responses = parse_multistatus(xml).unwrap();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].href, "/");
+        assert_eq!(responses[0].props["current-user-principal"], "/principals/users/jane/");
+    }
+
+    #[test]
+    fn parse_multistatus_reads_calendar_listing() {
+        // Default namespace on DAV:, a prefix on CalDAV, and an unprefixed Apple property
+        let xml = r##"<multistatus xmlns="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+              <response>
+                <href>/calendars/jane/</href>
+                <propstat><prop><resourcetype><collection/></resourcetype></prop></propstat>
+              </response>
+              <response>
+                <href>/calendars/jane/work/</href>
+                <propstat>
+                  <prop>
+                    <resourcetype><collection/><C:calendar/></resourcetype>
+                    <displayname>Work &amp; travel</displayname>
+                    <calendar-color xmlns="http://apple.com/ns/ical/">#FF2968FF</calendar-color>
+                    <C:supported-calendar-component-set><C:comp name="VEVENT"/><C:comp name="VTODO"/></C:supported-calendar-component-set>
+                  </prop>
+                </propstat>
+              </response>
+            </multistatus>"##;
+
+        let responses = parse_multistatus(xml).unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].resource_types, ["collection"]);
+        assert!(responses[0].components.is_empty());
+
+        let work = &responses[1];
+        assert_eq!(work.href, "/calendars/jane/work/");
+        assert_eq!(work.resource_types, ["collection", "calendar"]);
+        assert_eq!(work.components, ["VEVENT", "VTODO"]);
+        assert_eq!(work.props["displayname"], "Work & travel");
+        assert_eq!(work.props["calendar-color"], "#FF2968FF");
+    }
+
+    #[test]
+    fn parse_multistatus_reads_calendar_data() {
+        let xml = r#"<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+              <d:response>
+                <d:href>/calendars/jane/work/a.ics</d:href>
+                <d:propstat><d:prop><c:calendar-data><![CDATA[BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:a
+SUMMARY:Review
+DTSTART:20240110T150000Z
+END:VEVENT
+END:VCALENDAR]]></c:calendar-data></d:prop></d:propstat>
+              </d:response>
+              <d:response>
+                <d:href>/calendars/jane/work/b.ics</d:href>
+                <d:propstat><d:prop><c:calendar-data>BEGIN:VEVENT&#13;
+UID:b&#13;
+END:VEVENT</c:calendar-data></d:prop></d:propstat>
+              </d:response>
+            </d:multistatus>"#;
+
+        let responses = parse_multistatus(xml).unwrap();
+        assert_eq!(responses.len(), 2);
+        let events = parse_ics(&responses[0].props["calendar-data"]);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].summary.as_deref(), Some("Review"));
+        assert_eq!(parse_ics(&responses[1].props["calendar-data"])[0].uid, "b");
+    }
+
+    #[test]
+    fn parse_multistatus_rejects_malformed_xml() {
+        assert!(parse_multistatus("<d:multistatus xmlns:d=\"DAV:\"><d:response></d:multistatus>").is_err());
+    }
+
+    #[test]
+    fn summarize_deployments_counts_superseded_railway_deploys_as_successes() {
+        // Newest first: a live deploy, a skipped one, a crash, then older successes Railway has since removed
+        let deployments = ["SUCCESS", "SKIPPED", "CRASHED", "REMOVED", "REMOVED", "REMOVED", "SLEEPING"]
+            .iter()
+            .map(|status| deployment(normalize_railway_status(status), Some(60)))
+            .collect();
+
+        let history = summarize_deployments(deployments, 30);
+        assert_eq!(history.total_deployments, 7);
+        assert_eq!(history.failed_deployments, 1);
+        assert_eq!(history.canceled_deployments, 1);
+        assert_eq!(history.success_rate, 80.0);
+    }
+
+    #[test]
+    fn summarize_deployments_leaves_canceled_deploys_out_of_build_time() {
+        let deployments = vec![
+            deployment(DeploymentState::Canceled, Some(5)),
+            deployment(DeploymentState::Error, Some(50)),
+            deployment(DeploymentState::Ready, Some(100)),
+        ];
+
+        let history = summarize_deployments(deployments, 30);
+        assert_eq!(history.average_build_time, 75.0);
+        // Health follows the failed deploy behind the canceled one
+        assert_eq!(hosting_health(&history, 1, 1), "degraded");
+    }
+
+    #[test]
+    fn metric_snapshot_reads_single_web_analytics_entry() {
+        let old = r#"{"date":"2024-01-15","appId":"app","webAnalytics":{"provider":"plausible","visitors":10,"pageviews":20,"sessions":12,"bounceRate":40.0}}"#;
+        let snapshot: MetricSnapshot = serde_json::from_str(old).unwrap();
+        assert_eq!(snapshot.web_analytics.unwrap()[0].provider, "plausible");
+
+        let missing: MetricSnapshot = serde_json::from_str(r#"{"date":"2024-01-15","appId":"app"}"#).unwrap();
+        assert!(missing.web_analytics.is_none());
+    }
+
+    #[test]
+    fn certificate_not_after_reads_validity() {
+        let validity = [
+            &[0x30, 0x20, 0x17, 0x0d][..],
+            b"240101000000Z",
+            &[0x18, 0x0f],
+            b"20250301120000Z",
+        ]
+        .concat();
+        // Version, serial number, empty signature algorithm and issuer, then the validity period
+        let header = [0x30, 0x2e, 0xa0, 0x03, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01, 0x30, 0x00, 0x30, 0x00];
+        let tbs = [&header[..], &validity].concat();
+        let certificate = [&[0x30, 0x81, 0x30][..], &tbs].concat();
+
+        assert_eq!(certificate_not_after(&certificate), Some(utc(2025, 3, 1, 12)));
+        assert_eq!(certificate_not_after(&certificate[..20]), None);
+    }
+
+    #[test]
+    fn provider_deploy_states_normalise() {
+        let cases = [
+            (normalize_railway_status("SUCCESS"), DeploymentState::Ready),
+            (normalize_railway_status("REMOVED"), DeploymentState::Ready),
+            (normalize_railway_status("CRASHED"), DeploymentState::Error),
+            (normalize_railway_status("SKIPPED"), DeploymentState::Canceled),
+            (normalize_railway_status("INITIALIZING"), DeploymentState::Queued),
+            (normalize_railway_status("DEPLOYING"), DeploymentState::Building),
+            (normalize_railway_status("SLEEPING"), DeploymentState::Inactive),
+            (normalize_railway_status("NEEDS_APPROVAL"), DeploymentState::Unknown),
+            (normalize_render_status("live"), DeploymentState::Ready),
+            (normalize_render_status("deactivated"), DeploymentState::Ready),
+            (normalize_render_status("pre_deploy_failed"), DeploymentState::Error),
+            (normalize_render_status("canceled"), DeploymentState::Canceled),
+            (normalize_render_status("created"), DeploymentState::Queued),
+            (normalize_render_status("update_in_progress"), DeploymentState::Building),
+            (normalize_fly_release_status("Complete"), DeploymentState::Ready),
+            (normalize_fly_release_status("failed"), DeploymentState::Error),
+            (normalize_fly_release_status("interrupted"), DeploymentState::Canceled),
+            (normalize_fly_release_status("pending"), DeploymentState::Queued),
+            (normalize_fly_release_status("running"), DeploymentState::Building),
+            (normalize_fly_release_status("reverted"), DeploymentState::Unknown),
+        ];
+        for (index, (state, expected)) in cases.into_iter().enumerate() {
+            assert_eq!(state, expected, "case {}", index);
+        }
+    }
+
+    #[test]
+    fn idle_fly_machines_are_recognised() {
+        for (state, idle) in [("stopped", true), ("suspended", true), ("stopping", true), ("started", false), ("failed", false)] {
+            assert_eq!(is_idle_fly_machine(state), idle, "{}", state);
+        }
+    }
+
+    #[test]
+    fn summarize_deployments_computes_rates_and_build_times() {
+        use DeploymentState::*;
+        // (states newest first with build seconds, success rate, failed, canceled, average build time, status)
+        type Case = (&'static [(DeploymentState, Option<i64>)], f64, i32, i32, f64, &'static str);
+        let cases: [Case; 5] = [
+            (&[], 100.0, 0, 0, 0.0, "unknown"),
+            (&[(Ready, Some(60)), (Ready, Some(120))], 100.0, 0, 0, 90.0, "ready"),
+            (&[(Error, Some(30)), (Ready, Some(90)), (Ready, None), (Ready, Some(60))], 75.0, 1, 0, 60.0, "error"),
+            (&[(Building, None), (Canceled, Some(10)), (Ready, Some(40))], 100.0, 0, 1, 40.0, "building"),
+            (&[(Inactive, Some(500)), (Canceled, None)], 100.0, 0, 1, 0.0, "inactive"),
+        ];
+        for (index, (states, success_rate, failed, canceled, build_time, status)) in cases.into_iter().enumerate() {
+            let deployments = states.iter().map(|(state, duration)| deployment(*state, *duration)).collect();
+            let history = summarize_deployments(deployments, 30);
+            assert_eq!(history.total_deployments, states.len() as i32, "case {}", index);
+            assert_eq!(history.success_rate, success_rate, "case {}", index);
+            assert_eq!(history.failed_deployments, failed, "case {}", index);
+            assert_eq!(history.canceled_deployments, canceled, "case {}", index);
+            assert_eq!(history.average_build_time, build_time, "case {}", index);
+            assert_eq!(history.status, status, "case {}", index);
+        }
+    }
+
+    #[test]
+    fn hosting_health_combines_deploys_and_instances() {
+        use DeploymentState::*;
+        // (states newest first, running, total, health)
+        let cases: [(&[DeploymentState], i32, i32, &str); 7] = [
+            (&[Ready], 2, 2, "healthy"),
+            (&[Ready], 0, 2, "down"),
+            (&[Ready], 1, 2, "degraded"),
+            (&[Error, Ready], 2, 2, "degraded"),
+            (&[Building, Canceled, Ready], 1, 1, "healthy"),
+            (&[], 1, 1, "healthy"),
+            (&[Queued], 0, 0, "unknown"),
+        ];
+        for (index, (states, running, total, health)) in cases.into_iter().enumerate() {
+            let history = summarize_deployments(states.iter().map(|state| deployment(*state, None)).collect(), 30);
+            assert_eq!(hosting_health(&history, running, total), health, "case {}", index);
+        }
+    }
+
+    #[test]
+    fn user_growth_buckets_records_by_age() {
+        let now = chrono::Utc::now();
+        let record = |created_days: Option<i64>, active_days: Option<i64>, provider: &str| AuthUserRecord {
+            created_at: created_days.map(|days| now - chrono::Duration::hours(days * 24 + 1)),
+            last_active_at: active_days.map(|days| now - chrono::Duration::hours(days * 24 + 1)),
+            verified: created_days.is_some(),
+            provider: normalize_auth_provider(provider),
+        };
+        let records = [
+            record(Some(0), Some(0), "google"),
+            record(Some(3), Some(0), "password"),
+            record(Some(20), Some(5), "email"),
+            record(Some(40), Some(40), "github"),
+            record(None, None, "saml"),
+        ];
+
+        let growth = user_growth_from_records("supabase", 120, &records);
+        assert_eq!(growth.total_users, 120);
+        assert_eq!((growth.new_users_1d, growth.new_users_7d, growth.new_users_30d), (1, 2, 3));
+        assert_eq!(
+            (growth.active_users_1d, growth.active_users_7d, growth.active_users_30d),
+            (Some(2), Some(3), Some(3)),
+        );
+        assert_eq!(growth.verified_users, Some(4));
+
+        assert_eq!(growth.daily_signups.len(), USER_GROWTH_DAYS as usize);
+        assert_eq!(growth.daily_signups.iter().map(|d| d.count).sum::<i64>(), 3);
+        assert_eq!(growth.daily_signups.last().unwrap().date, now.format("%Y-%m-%d").to_string());
+
+        let providers: Vec<(&str, i32)> = growth
+            .auth_providers
+            .iter()
+            .map(|p| (p.provider.as_str(), p.count))
+            .collect();
+        assert_eq!(providers, [("email", 2), ("github", 1), ("google", 1), ("unknown", 1)]);
+    }
+
+    #[test]
+    fn umami_value_reads_both_stat_shapes() {
+        let cases = [
+            (serde_json::json!({ "value": 42, "prev": 30 }), 42),
+            (serde_json::json!(17), 17),
+            (serde_json::json!({ "prev": 30 }), 0),
+            (serde_json::json!(null), 0),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(umami_value(&value), expected, "{}", value);
+        }
+    }
+}
@@ -17,7 +17,8 @@ import { WidgetPicker } from './components/WidgetPicker';
 import { SettingsIcon, AnalyticsIcon } from './components/Icons';
 import { GmailInboxWidget } from './components/GmailInboxWidget';
 import { GitHubActivityWidget } from './components/GitHubActivityWidget';
-import { App, AppMetrics, Settings as SettingsType, DetailPanelType, Platform, GoogleCalendarConfig, CalendarEvent, WidgetType, GmailMessage, GmailMetrics, GitHubMetrics, GridLayoutItem } from './types';
+import { FrontendHealthWidget } from './components/FrontendHealthWidget';
+import { App, AppMetrics, Settings as SettingsType, DetailPanelType, Platform, GoogleCalendarConfig, CalendarEvent, WidgetType, GmailMessage, GmailMetrics, GitHubMetrics, GridLayoutItem, ErrorTrackingMetrics, NOTIFICATION_INTEGRATIONS } from './types';
 import { DashboardGrid, DEFAULT_LAYOUT, addWidgetToLayout } from './components/DashboardGrid';
 import { CardRect } from './components/StatCard';
 import { useHistory } from './hooks/useHistory';
@@ -128,6 +129,33 @@ function DashboardContent() {
     const allEvents: CalendarEvent[] = [];
 
     for (const app of settings.apps) {
+      // ICS / webcal subscriptions and local .ics files
+      if (app.calendarFeeds?.length) {
+        try {
+          const feedEventsJson = await invoke<string>('fetch_ics_calendars', {
+            feeds: JSON.stringify(app.calendarFeeds),
+          });
+          const feedEvents: CalendarEvent[] = JSON.parse(feedEventsJson);
+          // Prefer the feed's own colour over the project colour
+          allEvents.push(...feedEvents.map(e => ({ ...e, color: e.calendarColor || app.color })));
+        } catch (error) {
+          console.error(`Failed to fetch calendar feeds for ${app.name}:`, error);
+        }
+      }
+
+      // CalDAV accounts (discovers every calendar on the account)
+      if (app.caldavAccounts?.length) {
+        try {
+          const caldavEventsJson = await invoke<string>('fetch_caldav_calendars', {
+            accounts: JSON.stringify(app.caldavAccounts),
+          });
+          const caldavEvents: CalendarEvent[] = JSON.parse(caldavEventsJson);
+          allEvents.push(...caldavEvents.map(e => ({ ...e, color: e.calendarColor || app.color })));
+        } catch (error) {
+          console.error(`Failed to fetch CalDAV calendars for ${app.name}:`, error);
+        }
+      }
+
       const config = app.googleCalendar;
       if (!config?.enabled || !config?.accessToken) continue;
 
@@ -371,6 +399,19 @@ function DashboardContent() {
 
       setMetrics(results);
 
+      // GA4 refreshes the project's Google token when it has expired; keep the new one
+      const refreshedApps = settings.apps.map(app => {
+        const accessToken = results[app.id]?.googleAccessToken;
+        return accessToken && app.googleCalendar
+          ? { ...app, googleCalendar: { ...app.googleCalendar, accessToken } }
+          : app;
+      });
+      if (refreshedApps.some((app, index) => app !== settings.apps[index])) {
+        const newSettings = { ...settings, apps: refreshedApps };
+        setSettings(newSettings);
+        await saveSettingsToStore(newSettings);
+      }
+
       // Save snapshot for each app with metrics
       for (const [, appMetrics] of Object.entries(results)) {
         if (appMetrics) {
@@ -378,10 +419,31 @@ function DashboardContent() {
         }
       }
 
+      // Post the daily digest; the backend skips apps that already had one today.
+      // One at a time, since every send rewrites the shared digest state
+      for (const app of settings.apps) {
+        const appMetrics = results[app.id];
+        const hasNotifications = app.integrations.some(i => i.enabled && NOTIFICATION_INTEGRATIONS.includes(i.type));
+        if (appMetrics && hasNotifications) {
+          try {
+            await invoke('send_digest', {
+              app: JSON.stringify(app),
+              metrics: JSON.stringify(appMetrics),
+              force: false,
+            });
+          } catch (error) {
+            console.error(`Failed to send digest for ${app.name}:`, error);
+          }
+        }
+      }
+
       // Also refresh calendar events from all apps with calendar connected
       const hasAnyCalendarConnected = settings.apps.some(app => app.googleCalendar?.enabled);
-      if (hasAnyCalendarConnected) {
+      const hasAnyCalendarFeeds = settings.apps.some(app => app.calendarFeeds?.length || app.caldavAccounts?.length);
+      if (hasAnyCalendarConnected || hasAnyCalendarFeeds) {
         fetchAllCalendarEvents();
+      }
+      if (hasAnyCalendarConnected) {
         // Gmail uses the same OAuth, so fetch Gmail too
         fetchGmailData();
       }
@@ -407,7 +469,9 @@ function DashboardContent() {
 
   // Fetch calendar events when settings are loaded (for apps with calendar connected)
   useEffect(() => {
-    const hasAnyCalendarConnected = settings.apps.some(app => app.googleCalendar?.enabled && app.googleCalendar?.accessToken);
+    const hasAnyCalendarConnected = settings.apps.some(app =>
+      (app.googleCalendar?.enabled && app.googleCalendar?.accessToken) || app.calendarFeeds?.length || app.caldavAccounts?.length
+    );
     if (hasAnyCalendarConnected) {
       fetchAllCalendarEvents();
     }
@@ -569,6 +633,15 @@ function DashboardContent() {
     saveSettingsToStore(newSettings);
   }, [settings]);
 
+  // Error sources beside the deploy timeline; shared by the widget and the web platform tab
+  const frontendHealth = useMemo(() => (
+    <FrontendHealthWidget
+      errorSources={[currentMetrics?.sentry, currentMetrics?.logrocket].filter((s): s is ErrorTrackingMetrics => !!s)}
+      deployments={currentMetrics?.deployTimeline?.deployments || []}
+      isLoading={isLoading}
+    />
+  ), [currentMetrics, isLoading]);
+
   // Create widget map for DashboardGrid
   const widgetMap = useMemo(() => ({
     // Stat cards
@@ -661,7 +734,7 @@ function DashboardContent() {
     'activity-feed': (
       <ActivityFeed
         events={currentMetrics?.stripeEvents || []}
-        deployments={(currentMetrics?.vercel as import('./types').VercelMetricsExtended)?.deployments || []}
+        deployments={currentMetrics?.deployTimeline?.deployments || []}
         onViewAll={() => handleOpenPanel('stripe-events')}
       />
     ),
@@ -682,6 +755,8 @@ function DashboardContent() {
         onViewAll={() => handleOpenPanel('github')}
       />
     ),
+    // Frontend Health
+    'frontend-health': frontendHealth,
     // Add Widget placeholder
     'add-widget': (
       <AddWidgetCard onClick={() => setShowWidgetPicker(true)} />
@@ -690,7 +765,7 @@ function DashboardContent() {
     displayMRR, mrrChange, displayTotalUsers, displayPayingUsers, subscriberChange,
     displayChurn, isLoading, effectiveMetrics, revenueChartData, subscriberChartData,
     currentMetrics, calendarEvents, gmailData, githubData, tokens.colors,
-    handleOpenPanel
+    handleOpenPanel, frontendHealth
   ]);
 
   return (
@@ -947,13 +1022,21 @@ function DashboardContent() {
                 </button>
               </div>
             ) : (
-              /* Dashboard Grid - always use grid, edit mode controls drag/resize */
-              <DashboardGrid
-                layout={gridLayout}
-                onLayoutChange={handleLayoutChange}
-                widgetMap={widgetMap}
-                isEditMode={isEditMode}
-              />
+              <>
+                {/* Web platform: frontend health beside the deploys, unless it's already a widget */}
+                {activePlatformTab === 'web' && !gridLayout.some(item => item.i === 'frontend-health') && (
+                  <div style={{ height: '250px', marginBottom: '16px' }}>
+                    {frontendHealth}
+                  </div>
+                )}
+                {/* Dashboard Grid - always use grid, edit mode controls drag/resize */}
+                <DashboardGrid
+                  layout={gridLayout}
+                  onLayoutChange={handleLayoutChange}
+                  widgetMap={widgetMap}
+                  isEditMode={isEditMode}
+                />
+              </>
             )}
           </>
         )}
@@ -1042,6 +1125,7 @@ function DashboardContent() {
             'stat-analytics-dau': 'stat-analytics-dau',
             'stat-analytics-mau': 'stat-analytics-mau',
             'stat-analytics-wau': 'stat-analytics-wau',
+            'frontend-health': 'frontend-health',
           };
           const widgetId = widgetIdMap[widgetType] || widgetType;
           const newLayout = addWidgetToLayout(gridLayout, widgetId);
@@ -207,12 +207,17 @@ function convertStripeEvent(event: StripeEvent): ActivityEvent {
   };
 }
 
-// Convert Vercel deployment to activity event
+// Only finished deploys are activity; queued, building and canceled ones are neither
+function isFinishedDeployment(deployment: Deployment): boolean {
+  return deployment.state === 'READY' || deployment.state === 'ERROR';
+}
+
+// Convert a hosting provider deployment to activity event
 function convertDeployment(deployment: Deployment): ActivityEvent {
   const isSuccess = deployment.state === 'READY';
   return {
     id: deployment.id,
-    source: 'vercel',
+    source: deployment.provider,
     type: isSuccess ? 'deployment_success' : 'deployment_failed',
     timestamp: Math.floor(new Date(deployment.createdAt).getTime() / 1000),
     title: isSuccess ? 'Deployment successful' : 'Deployment failed',
@@ -229,7 +234,7 @@ export function ActivityFeed({ events = [], activityEvents = [], deployments = [
   const allEvents: ActivityEvent[] = [
     ...activityEvents,
     ...events.map(convertStripeEvent),
-    ...deployments.slice(0, 5).map(convertDeployment),
+    ...deployments.filter(isFinishedDeployment).slice(0, 5).map(convertDeployment),
   ].sort((a, b) => b.timestamp - a.timestamp);
 
   const displayEvents = allEvents.slice(0, 6);
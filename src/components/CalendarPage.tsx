@@ -46,7 +46,7 @@ export function CalendarPage({ apps, events, googleCalendarEvents = [] }: Calend
       allEvents.push({
         ...event,
         appId: 'google',
-        appName: 'Google Calendar',
+        appName: event.source === 'google' ? 'Google Calendar' : event.calendarName || 'Calendar',
         color: event.color || '#4285f4', // Use project color if set, fallback to Google blue
       });
     });
@@ -46,6 +46,8 @@ const WIDGET_CONFIGS: Record<string, WidgetConfig> = {
   // Gmail & GitHub
   'gmail-inbox': { minW: 1, minH: 3, defaultW: 2, defaultH: 5 },
   'github-activity': { minW: 1, minH: 3, defaultW: 2, defaultH: 5 },
+  // Error tracking
+  'frontend-health': { minW: 1, minH: 3, defaultW: 2, defaultH: 5 },
   // Add widget placeholder
   'add-widget': { minW: 1, minH: 2, defaultW: 1, defaultH: 3 },
 };
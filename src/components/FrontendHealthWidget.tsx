@@ -0,0 +1,148 @@
+import React from 'react';
+import { useTheme } from '../contexts/ThemeContext';
+import { Deployment, ErrorTrackingMetrics } from '../types';
+
+interface FrontendHealthWidgetProps {
+  errorSources?: ErrorTrackingMetrics[]; // Sentry, LogRocket
+  deployments?: Deployment[];
+  isLoading?: boolean;
+}
+
+const SOURCE_LABELS: Record<string, string> = {
+  sentry: 'Sentry',
+  logrocket: 'LogRocket',
+};
+
+const stateColors: Record<string, string> = {
+  READY: '#22c55e',
+  ERROR: '#ef4444',
+  BUILDING: '#eab308',
+  QUEUED: '#eab308',
+  CANCELED: '#6b7280',
+};
+
+function formatTime(timestamp: string): string {
+  const date = new Date(timestamp);
+  const diff = Date.now() - date.getTime();
+  const minutes = Math.floor(diff / 60000);
+  const hours = Math.floor(diff / 3600000);
+  const days = Math.floor(diff / 86400000);
+
+  if (minutes < 1) return 'Just now';
+  if (minutes < 60) return `${minutes}m ago`;
+  if (hours < 24) return `${hours}h ago`;
+  if (days < 7) return `${days}d ago`;
+  return date.toLocaleDateString();
+}
+
+// Metrics a source didn't report stay as a dash rather than a misleading zero
+function formatCount(value: number | null | undefined): string {
+  return value === null || value === undefined ? '—' : value.toLocaleString();
+}
+
+export function FrontendHealthWidget({ errorSources = [], deployments = [], isLoading = false }: FrontendHealthWidgetProps) {
+  const { tokens } = useTheme();
+
+  // Latest production deploy, so error spikes can be read against what just shipped
+  const latestDeploy = deployments.find(d => d.environment === 'production') || deployments[0];
+
+  const containerStyle: React.CSSProperties = {
+    background: tokens.colors.bgCard,
+    border: `1px solid ${tokens.colors.border}`,
+    borderRadius: tokens.radius.lg,
+    padding: '20px',
+    backdropFilter: 'blur(20px)',
+    height: '100%',
+    display: 'flex',
+    flexDirection: 'column',
+  };
+
+  const header = (
+    <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginBottom: '16px' }}>
+      <span style={{ fontWeight: 500, fontSize: '14px', color: tokens.colors.text }}>Frontend Health</span>
+    </div>
+  );
+
+  if (isLoading) {
+    return (
+      <div style={containerStyle}>
+        {header}
+        {[1, 2, 3].map((i) => (
+          <div key={i} style={{ height: '14px', width: `${60 + i * 10}%`, background: tokens.colors.bgCardHover, borderRadius: '4px', marginBottom: '12px' }} />
+        ))}
+      </div>
+    );
+  }
+
+  const stat = (label: string, value: string) => (
+    <div style={{ flex: 1, minWidth: 0 }}>
+      <div style={{ fontSize: '11px', color: tokens.colors.textDim, marginBottom: '2px' }}>{label}</div>
+      <div style={{ fontSize: '15px', fontWeight: 500, color: tokens.colors.text }}>{value}</div>
+    </div>
+  );
+
+  return (
+    <div style={containerStyle}>
+      {header}
+
+      {latestDeploy && (
+        <div
+          style={{
+            display: 'flex',
+            alignItems: 'center',
+            gap: '8px',
+            paddingBottom: '12px',
+            marginBottom: '12px',
+            borderBottom: `1px solid ${tokens.colors.border}`,
+            fontSize: '12px',
+            color: tokens.colors.textMuted,
+          }}
+        >
+          <span style={{ width: '8px', height: '8px', borderRadius: '50%', background: stateColors[latestDeploy.state] || stateColors.CANCELED, flexShrink: 0 }} />
+          <span style={{ flex: 1, overflow: 'hidden', textOverflow: 'ellipsis', whiteSpace: 'nowrap' }}>
+            Last deploy {latestDeploy.state.toLowerCase()} · {latestDeploy.commitMessage || latestDeploy.name}
+          </span>
+          <span style={{ fontSize: '11px', color: tokens.colors.textDim, whiteSpace: 'nowrap' }}>
+            {formatTime(latestDeploy.createdAt)}
+          </span>
+        </div>
+      )}
+
+      <div style={{ flex: 1, overflow: 'auto' }}>
+        {errorSources.length === 0 && (
+          <p style={{ fontSize: '13px', color: tokens.colors.textMuted }}>Connect Sentry or LogRocket to track frontend errors</p>
+        )}
+        {errorSources.map((source, index) => {
+          const topIssue = source.topIssues[0];
+          return (
+            <div
+              key={source.provider}
+              style={{
+                padding: '8px 0',
+                borderBottom: index < errorSources.length - 1 ? `1px solid ${tokens.colors.border}` : undefined,
+              }}
+            >
+              <div style={{ fontSize: '12px', color: tokens.colors.textMuted, marginBottom: '8px' }}>
+                {SOURCE_LABELS[source.provider] || source.provider}
+              </div>
+              <div style={{ display: 'flex', gap: '12px', marginBottom: '8px' }}>
+                {stat('Errors (24h)', formatCount(source.events24h))}
+                {source.sessions24h !== null
+                  ? stat('Sessions (24h)', formatCount(source.sessions24h))
+                  : stat('Unresolved', formatCount(source.unresolvedIssues))}
+                {source.friction
+                  ? stat('Rage clicks', formatCount(source.friction.rageClicks24h))
+                  : stat('Crash-free', source.crashFreeRate === null ? '—' : `${source.crashFreeRate.toFixed(1)}%`)}
+              </div>
+              {topIssue && (
+                <div style={{ fontSize: '12px', color: tokens.colors.textDim, overflow: 'hidden', textOverflow: 'ellipsis', whiteSpace: 'nowrap' }}>
+                  Top issue: {topIssue.title} ({topIssue.count.toLocaleString()})
+                </div>
+              )}
+            </div>
+          );
+        })}
+      </div>
+    </div>
+  );
+}
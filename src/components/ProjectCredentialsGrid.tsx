@@ -6,8 +6,13 @@ import { IntegrationConfigForm, CredentialValues, INTEGRATION_NAMES } from './In
 // Credential config per project
 export interface ProjectCredentialConfig {
   apiKey?: string;
+  apiSecret?: string;
   projectId?: string;
   teamId?: string;
+  baseUrl?: string;
+  eventName?: string;
+  metricIds?: string;
+  serviceId?: string;
   skip: boolean;
   googleCalendar?: GoogleCalendarConfig; // For OAuth integrations
 }
@@ -34,8 +39,13 @@ export function ProjectCredentialsGrid({
       const existingIntegration = app.integrations.find(i => i.type === integrationType);
       initial[app.id] = {
         apiKey: existingIntegration?.apiKey || '',
+        apiSecret: existingIntegration?.apiSecret || '',
         projectId: existingIntegration?.projectId || '',
         teamId: existingIntegration?.teamId || '',
+        baseUrl: existingIntegration?.baseUrl || '',
+        eventName: existingIntegration?.eventName || '',
+        metricIds: existingIntegration?.metricIds || '',
+        serviceId: existingIntegration?.serviceId || '',
         skip: false,
         googleCalendar: app.googleCalendar,
       };
@@ -100,8 +110,9 @@ export function ProjectCredentialsGrid({
         {apps.map(app => {
           const creds = credentials[app.id];
           const isSkipped = creds?.skip;
+          // Google Analytics still needs its property ID even when Google is already connected
           const hasExistingConfig = isOAuth
-            ? creds?.googleCalendar?.enabled
+            ? creds?.googleCalendar?.enabled && integrationType !== 'google_analytics'
             : creds?.apiKey;
 
           return (
@@ -191,8 +202,13 @@ export function ProjectCredentialsGrid({
                   integrationType={integrationType}
                   initialValues={{
                     apiKey: creds?.apiKey,
+                    apiSecret: creds?.apiSecret,
                     projectId: creds?.projectId,
                     teamId: creds?.teamId,
+                    baseUrl: creds?.baseUrl,
+                    eventName: creds?.eventName,
+                    metricIds: creds?.metricIds,
+                    serviceId: creds?.serviceId,
                   }}
                   onValuesChange={(values) => handleValuesChange(app.id, values)}
                   onOAuthSuccess={(config) => handleOAuthSuccess(app.id, config)}
@@ -29,6 +29,8 @@ const WIDGET_INTEGRATIONS: Record<WidgetType, IntegrationType[]> = {
   'stat-analytics-dau': ['posthog', 'google_analytics'],
   'stat-analytics-mau': ['posthog', 'google_analytics'],
   'stat-analytics-wau': ['posthog', 'google_analytics'],
+  // Error tracking widgets
+  'frontend-health': ['sentry', 'logrocket'],
 };
 
 interface WidgetOption {
@@ -242,11 +244,32 @@ const widgetOptions: WidgetOption[] = [
       </svg>
     ),
   },
+  // Error tracking widgets
+  {
+    type: 'frontend-health',
+    name: 'Frontend Health',
+    description: 'Errors and friction next to the latest deploy',
+    category: 'other',
+    icon: (
+      <svg width="20" height="20" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+        <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={1.5} d="M9 12l2 2 4-4m5.618-4.016A11.955 11.955 0 0112 2.944a11.955 11.955 0 01-8.618 3.04A12.02 12.02 0 003 9c0 5.591 3.824 10.29 9 11.622 5.176-1.332 9-6.03 9-11.622 0-1.042-.133-2.052-.382-3.016z" />
+      </svg>
+    ),
+  },
 ];
 
 // Step types for multi-step flow
 type Step = 'select' | 'configure';
 
+// Google Analytics reads through the project's Google connection; its integration only holds the GA4 property
+function withAnalyticsProperty(integrations: Integration[], propertyId?: string): Integration[] {
+  if (!propertyId) return integrations;
+  const integration: Integration = { type: 'google_analytics', projectId: propertyId, enabled: true };
+  return integrations.some(i => i.type === 'google_analytics')
+    ? integrations.map(i => i.type === 'google_analytics' ? { ...i, ...integration } : i)
+    : [...integrations, integration];
+}
+
 interface WidgetPickerProps {
   isOpen: boolean;
   onClose: () => void;
@@ -316,7 +339,7 @@ export function WidgetPicker({
     }
 
     const hasAny = requiredIntegrations.some(reqType =>
-      appIntegrations.some(i => i.type === reqType && i.enabled && i.apiKey)
+      appIntegrations.some(i => i.type === reqType && i.enabled && (i.apiKey || (i.type === 'google_analytics' && i.projectId)))
     );
 
     if (hasAny) {
@@ -373,7 +396,8 @@ export function WidgetPicker({
 
     if (context === 'project') {
       if (isOAuth) {
-        return singleOAuthConfig?.enabled === true;
+        return singleOAuthConfig?.enabled === true
+          && (requiredIntegration !== 'google_analytics' || !!singleCredentials.projectId);
       }
       // For API key integrations, check required fields
       return !!singleCredentials.apiKey;
@@ -381,7 +405,9 @@ export function WidgetPicker({
       // Overview: at least one project must be configured
       return Object.values(multiCredentials).some(c => {
         if (c.skip) return false;
-        if (isOAuth) return c.googleCalendar?.enabled;
+        if (isOAuth) {
+          return c.googleCalendar?.enabled && (requiredIntegration !== 'google_analytics' || !!c.projectId);
+        }
         return !!c.apiKey;
       });
     }
@@ -402,15 +428,23 @@ export function WidgetPicker({
         updatedApp = {
           ...currentApp,
           googleCalendar: singleOAuthConfig || currentApp.googleCalendar,
+          integrations: requiredIntegration === 'google_analytics'
+            ? withAnalyticsProperty(currentApp.integrations, singleCredentials.projectId)
+            : currentApp.integrations,
         };
       } else {
         // For API key, add/update integration
         const existingIndex = currentApp.integrations.findIndex(i => i.type === requiredIntegration);
         const newIntegration: Integration = {
           type: requiredIntegration,
-          apiKey: singleCredentials.apiKey,
-          projectId: singleCredentials.projectId,
-          teamId: singleCredentials.teamId,
+          apiKey: singleCredentials.apiKey || undefined,
+          apiSecret: singleCredentials.apiSecret || undefined,
+          projectId: singleCredentials.projectId || undefined,
+          teamId: singleCredentials.teamId || undefined,
+          baseUrl: singleCredentials.baseUrl || undefined,
+          eventName: singleCredentials.eventName || undefined,
+          metricIds: singleCredentials.metricIds || undefined,
+          serviceId: singleCredentials.serviceId || undefined,
           enabled: true,
         };
 
@@ -432,16 +466,27 @@ export function WidgetPicker({
 
         if (isOAuth) {
           if (!creds.googleCalendar?.enabled) return app;
-          return { ...app, googleCalendar: creds.googleCalendar };
+          return {
+            ...app,
+            googleCalendar: creds.googleCalendar,
+            integrations: requiredIntegration === 'google_analytics'
+              ? withAnalyticsProperty(app.integrations, creds.projectId)
+              : app.integrations,
+          };
         } else {
           if (!creds.apiKey) return app;
 
           const existingIndex = app.integrations.findIndex(i => i.type === requiredIntegration);
           const newIntegration: Integration = {
             type: requiredIntegration,
-            apiKey: creds.apiKey,
-            projectId: creds.projectId,
-            teamId: creds.teamId,
+            apiKey: creds.apiKey || undefined,
+            apiSecret: creds.apiSecret || undefined,
+            projectId: creds.projectId || undefined,
+            teamId: creds.teamId || undefined,
+            baseUrl: creds.baseUrl || undefined,
+            eventName: creds.eventName || undefined,
+            metricIds: creds.metricIds || undefined,
+            serviceId: creds.serviceId || undefined,
             enabled: true,
           };
 
@@ -7,22 +7,26 @@ const IS_TAURI = typeof window !== 'undefined' && '__TAURI_INTERNALS__' in windo
 
 export type OAuthStatus = 'idle' | 'connecting' | 'success' | 'error';
 
+interface GoogleOAuthOptions {
+  analytics?: boolean; // Also request the Google Analytics read-only scope
+}
+
 interface UseGoogleOAuthReturn {
   status: OAuthStatus;
   error: string | null;
-  connect: () => Promise<GoogleCalendarConfig | null>;
+  connect: (options?: GoogleOAuthOptions) => Promise<GoogleCalendarConfig | null>;
   reset: () => void;
 }
 
 /**
- * Hook for handling Google OAuth flow (Calendar + Gmail)
+ * Hook for handling Google OAuth flow (Calendar + Gmail, optionally Analytics)
  * Extracted from Settings.tsx for reuse in WidgetPicker
  */
 export function useGoogleOAuth(): UseGoogleOAuthReturn {
   const [status, setStatus] = useState<OAuthStatus>('idle');
   const [error, setError] = useState<string | null>(null);
 
-  const connect = useCallback(async (): Promise<GoogleCalendarConfig | null> => {
+  const connect = useCallback(async (options: GoogleOAuthOptions = {}): Promise<GoogleCalendarConfig | null> => {
     if (!IS_TAURI) {
       setStatus('error');
       setError('Google connection only works in the desktop app');
@@ -34,7 +38,9 @@ export function useGoogleOAuth(): UseGoogleOAuthReturn {
 
     try {
       // This opens the browser, waits for OAuth callback, and returns tokens
-      const tokenResponse = await invoke<string>('start_google_oauth');
+      const tokenResponse = await invoke<string>('start_google_oauth', {
+        analytics: options.analytics ?? false,
+      });
       const tokens = JSON.parse(tokenResponse);
 
       const config: GoogleCalendarConfig = {
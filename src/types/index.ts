@@ -11,6 +11,8 @@ export interface App {
   googleCalendar?: GoogleCalendarConfig; // Per-project Google Calendar connection (legacy)
   googleAuth?: GoogleAuthConfig; // Unified Google auth for Calendar + Gmail
   github?: GitHubConfig; // GitHub integration config
+  calendarFeeds?: CalendarFeed[]; // ICS / webcal subscriptions and local .ics files
+  caldavAccounts?: CalDavAccount[]; // Fastmail, Nextcloud, iCloud, ...
 }
 
 // Predefined project colors
@@ -30,10 +32,33 @@ export interface Integration {
   apiKey?: string;
   projectId?: string;
   teamId?: string; // For Vercel: team slug or ID (e.g., "greatjay24-projects")
+  apiSecret?: string; // Secondary credential (e.g., Amplitude secret key, Supabase personal access token)
+  baseUrl?: string; // Region or self-hosted API base URL override (e.g., self-hosted Sentry)
+  eventName?: string; // Key event to segment on (Amplitude)
+  metricIds?: string; // Saved metric IDs by role, e.g. errors=123, sessions=456 (LogRocket)
+  serviceId?: string; // Service within the project (Railway)
+  historyDays?: number; // How far back to page through deployment history (default 30)
+  collections?: string[]; // Firestore collections to count
+  queries?: SqlMetricQuery[]; // Named read-only queries (Postgres; connection string goes in apiKey)
   enabled: boolean;
   platform?: Platform; // Which platform this integration belongs to (for filtering)
 }
 
+// Scalar queries return one row with a `value` column; time series return `date` and `value` per row
+export interface SqlMetricQuery {
+  name: string;
+  sql: string;
+  kind?: 'scalar' | 'timeseries';
+}
+
+export interface CustomMetric {
+  name: string;
+  kind: 'scalar' | 'timeseries';
+  value: number | null; // Latest point for time series
+  series: { date: string; value: number }[];
+  error: string | null;
+}
+
 export type IntegrationType =
   // Payments & Revenue
   | 'stripe'
@@ -46,6 +71,8 @@ export type IntegrationType =
   | 'mixpanel'
   | 'amplitude'
   | 'plausible'
+  | 'umami'
+  | 'fathom'
   | 'google_analytics'
   // Hosting & Deployment
   | 'vercel'
@@ -56,6 +83,7 @@ export type IntegrationType =
   // Database & Backend
   | 'supabase'
   | 'firebase'
+  | 'postgres'
   | 'planetscale'
   | 'neon'
   | 'mongodb'
@@ -153,7 +181,7 @@ export interface StripeEvent {
 // Unified Activity Event Types
 // ==========================================
 
-export type ActivitySource = 'stripe' | 'supabase' | 'vercel' | 'system';
+export type ActivitySource = 'stripe' | 'supabase' | 'vercel' | 'netlify' | 'railway' | 'render' | 'flyio' | 'system';
 
 export type ActivityType =
   // Stripe events
@@ -216,7 +244,7 @@ export interface CalendarEvent {
   title: string;
   startTime: string;
   endTime: string;
-  source: 'google' | 'stripe' | 'custom';
+  source: 'google' | 'cal' | 'ics' | 'caldav' | 'stripe' | 'custom';
   type: 'meeting' | 'deadline' | 'renewal' | 'trial_end' | 'payout' | 'other';
   description?: string;
   url?: string;
@@ -225,6 +253,19 @@ export interface CalendarEvent {
   calendarColor?: string; // Color of the Google Calendar (hex, e.g., "#4285f4")
 }
 
+export interface CalendarFeed {
+  url: string; // https://, webcal://, file:// or a local path
+  name: string;
+  color?: string; // Hex, e.g. "#0078d4"
+}
+
+export interface CalDavAccount {
+  serverUrl: string; // e.g. "https://caldav.fastmail.com/"
+  username: string;
+  password: string; // App password
+  color?: string; // Overrides each calendar's own colour
+}
+
 export interface GoogleCalendarConfig {
   enabled: boolean;
   accessToken?: string;
@@ -343,6 +384,29 @@ export interface AnalyticsEvent {
   change: number; // % change from previous period
 }
 
+export interface RankedItem {
+  name: string;
+  count: number;
+}
+
+export interface WebAnalyticsDay {
+  date: string; // YYYY-MM-DD
+  visitors: number;
+  pageviews: number;
+}
+
+// Shared by PostHog, Plausible, Google Analytics, Umami and Fathom
+export interface WebAnalyticsMetrics {
+  provider: string;
+  visitors: number;
+  pageviews: number;
+  sessions: number;
+  bounceRate: number; // Percentage (0-100)
+  topPages: RankedItem[];
+  topSources: RankedItem[];
+  daily: WebAnalyticsDay[];
+}
+
 // ==========================================
 // Vercel Types (Extended)
 // ==========================================
@@ -361,14 +425,39 @@ export interface VercelMetricsExtended extends VercelMetrics {
   domainsCount: number;
 }
 
+export type DeploymentProvider = 'vercel' | 'netlify' | 'railway' | 'render' | 'flyio';
+
+export type DeploymentState = 'QUEUED' | 'BUILDING' | 'READY' | 'ERROR' | 'CANCELED' | 'INACTIVE' | 'UNKNOWN';
+
 export interface Deployment {
   id: string;
+  provider: DeploymentProvider;
   name: string;
-  state: string;
+  environment: 'production' | 'preview';
+  state: DeploymentState;
   createdAt: string;
+  startedAt?: string;
+  finishedAt?: string;
+  duration?: number; // Seconds
   url: string;
-  buildDuration?: number;
-  source?: string;
+  commitSha?: string;
+  commitMessage?: string;
+  commitAuthor?: string;
+}
+
+// Every host's deployments merged into one timeline
+export interface DeploymentTimeline {
+  deployments: Deployment[];
+  lastDeployedAt: string | null;
+  status: string;
+  windowDays: number;
+  totalDeployments: number;
+  failedDeployments: number;
+  canceledDeployments: number;
+  productionDeployments: number;
+  previewDeployments: number;
+  successRate: number;
+  averageBuildTime: number;
 }
 
 // ==========================================
@@ -401,15 +490,181 @@ export interface EventCount {
 // Supabase Types
 // ==========================================
 
+export interface AuthProviderCount {
+  provider: AuthProvider;
+  count: number;
+}
+
 export interface SupabaseMetrics {
   totalUsers: number;
-  newUsers7d: number;
+  newUsers7d: number; // Full user breakdown lives in AppMetrics.users
   databaseSize: string;
   apiRequests24h: number;
+  // Management API usage, present when a personal access token is set as apiSecret
+  databaseSizeBytes?: number;
+  storageSizeBytes?: number;
+  egressBytes24h?: number;
+  apiRequestsByService?: {
+    rest: number;
+    auth: number;
+    storage: number;
+    realtime: number;
+  };
 }
 
+// Shared by every auth provider: Supabase, Firebase, Clerk and Auth0
+export interface UserGrowthMetrics {
+  provider: 'supabase' | 'firebase' | 'clerk' | 'auth0';
+  totalUsers: number;
+  newUsers1d: number;
+  newUsers7d: number;
+  newUsers30d: number;
+  dailySignups: { date: string; count: number }[]; // Last 30 days, oldest first
+  authProviders: AuthProviderCount[];
+  verifiedUsers: number | null;
+  // null when the provider doesn't track sign-in activity
+  activeUsers1d: number | null;
+  activeUsers7d: number | null;
+  activeUsers30d: number | null;
+}
+
+export interface FirebaseMetrics {
+  totalUsers: number;
+  newUsers7d: number; // Full user breakdown lives in AppMetrics.users
+  collections: { collection: string; count: number }[];
+}
+
+export interface DatabaseInstance {
+  name: string;
+  status: 'ready' | 'sleeping' | 'provisioning' | 'paused' | 'error' | 'unknown';
+  region: string | null;
+  primary: boolean;
+  storageBytes: number | null;
+}
+
+// Shared by PlanetScale, Neon and MongoDB Atlas
+export interface DatabaseHealthMetrics {
+  provider: 'planetscale' | 'neon' | 'mongodb';
+  health: 'healthy' | 'degraded' | 'down' | 'unknown';
+  instances: DatabaseInstance[];
+  storageUsedBytes: number | null;
+  storageLimitBytes: number | null;
+  computeHours: number | null; // Current billing period
+  connections: number | null;
+}
+
+export interface ErrorIssue {
+  id: string;
+  title: string;
+  culprit: string | null;
+  level: string;
+  count: number; // Events in the stats window
+  userCount: number;
+  firstSeen: string | null;
+  lastSeen: string | null;
+  url: string | null;
+}
+
+export interface ReleaseHealth {
+  version: string;
+  sessions: number;
+  crashFreeRate: number | null; // Percentage (0-100)
+}
+
+// Shared by every error source
+export interface ErrorTrackingMetrics {
+  provider: string;
+  unresolvedIssues: number | null;
+  newIssues24h: number | null;
+  events24h: number;
+  dailyEvents: { date: string; count: number }[]; // Oldest first
+  crashFreeRate: number | null; // Percentage of sessions (0-100)
+  releases: ReleaseHealth[];
+  topIssues: ErrorIssue[];
+  sessions24h: number | null; // Session replay tools only
+  friction: FrictionSignals | null;
+}
+
+export interface FrictionSignals {
+  rageClicks24h: number | null;
+  deadClicks24h: number | null;
+  errorSessions24h: number | null;
+}
+
+export interface EmailDeliveryCounts {
+  sent: number;
+  delivered: number;
+  bounced: number;
+  complained: number;
+  opened: number;
+}
+
+export interface EmailDomain {
+  name: string;
+  status: string;
+  verified: boolean;
+}
+
+// Shared by Resend and SendGrid
+export interface EmailDeliveryMetrics {
+  provider: string;
+  today: EmailDeliveryCounts; // Since 00:00 UTC
+  last7d: EmailDeliveryCounts;
+  deliveryRate: number | null; // Percentage of sent over 7d (0-100)
+  bounceRate: number | null;
+  domains: EmailDomain[];
+}
+
+export interface NewsletterCampaign {
+  id: string;
+  subject: string;
+  sentAt: string | null;
+  recipients: number;
+  openRate: number | null; // Percentage (0-100)
+  clickRate: number | null;
+}
+
+// Shared by ConvertKit and Mailchimp
+export interface NewsletterMetrics {
+  provider: string;
+  totalSubscribers: number;
+  newSubscribers30d: number;
+  unsubscribed30d: number;
+  subscriberGrowth: { date: string; count: number }[]; // List size per day, oldest first
+  latestCampaign: NewsletterCampaign | null;
+}
+
+// Shared by Intercom and Crisp
+export interface SupportMetrics {
+  provider: string;
+  openConversations: number;
+  unassignedConversations: number | null;
+  newConversations7d: number;
+  dailyNewConversations: { date: string; count: number }[]; // Oldest first
+  medianFirstResponseSeconds: number | null;
+}
+
+export interface CommunityMetrics {
+  provider: string;
+  name: string;
+  totalMembers: number;
+  onlineMembers: number;
+  // null when the bot lacks the Server Members intent or the server is too large to list
+  newMembers7d: number | null;
+  newMembers30d: number | null;
+  dailyJoins: { date: string; count: number }[] | null; // Oldest first
+}
+
+export interface BookingMetrics {
+  provider: string;
+  upcomingBookings: number;
+  bookings30d: number; // Started in the last 30 days
+  cancellations30d: number;
+  noShows30d: number;
+}
+
+// User activity lives in AppMetrics.users (UserGrowthMetrics.activeUsers7d)
 export interface SupabaseMetricsExtended extends SupabaseMetrics {
-  activeUsers7d: number;
   storageUsed: string;
   storageLimit: string;
   bandwidthUsed: string;
@@ -426,8 +681,22 @@ export interface AppMetrics {
   vercel?: VercelMetrics | VercelMetricsExtended;
   posthog?: PostHogMetrics | PostHogMetricsExtended;
   supabase?: SupabaseMetrics | SupabaseMetricsExtended;
+  firebase?: FirebaseMetrics;
+  users?: UserGrowthMetrics[]; // One entry per auth provider
+  webAnalytics?: WebAnalyticsMetrics[]; // One entry per web analytics provider
+  database?: DatabaseHealthMetrics[]; // One entry per database host
+  sentry?: ErrorTrackingMetrics;
+  logrocket?: ErrorTrackingMetrics;
+  email?: EmailDeliveryMetrics[]; // One entry per email provider
+  newsletter?: NewsletterMetrics[]; // One entry per newsletter provider
+  support?: SupportMetrics[]; // One entry per support provider
+  community?: CommunityMetrics;
+  bookings?: BookingMetrics;
+  customMetrics?: CustomMetric[];
   stripeEvents?: StripeEvent[];
-  calendarEvents?: CalendarEvent[];
+  deployTimeline?: DeploymentTimeline;
+  deployActivity?: ActivityEvent[];
+  calendarEvents?: CalendarEvent[]; // Cal.com bookings
   // Gmail metrics
   gmail?: GmailMetrics;
   gmailMessages?: GmailMessage[];
@@ -435,6 +704,7 @@ export interface AppMetrics {
   github?: GitHubMetrics;
   // Analytics (DAU/MAU/WAU)
   analytics?: AnalyticsMetrics;
+  googleAccessToken?: string; // Refreshed Google token to store on the app's googleCalendar
   lastUpdated: string;
 }
 
@@ -462,6 +732,47 @@ export interface MetricSnapshot {
   supabase?: {
     totalUsers: number;
     apiRequests: number;
+    databaseSizeBytes?: number;
+    storageSizeBytes?: number;
+    egressBytes?: number;
+  };
+  webAnalytics?: {
+    provider: string;
+    visitors: number;
+    pageviews: number;
+    sessions: number;
+    bounceRate: number;
+  }[];
+  customMetrics?: { name: string; value: number }[];
+  users?: {
+    provider: string;
+    totalUsers: number;
+    newUsers: number; // Last 24h
+    activeUsers: number | null; // Last 30 days
+  };
+  database?: {
+    provider: string;
+    health: string;
+    storageUsedBytes: number | null;
+    computeHours: number | null;
+    connections: number | null;
+  }[];
+  sentry?: {
+    unresolvedIssues: number | null;
+    newIssues: number | null;
+    events: number;
+    crashFreeRate: number | null;
+  };
+  newsletter?: {
+    provider: string;
+    subscribers: number;
+    newSubscribers: number; // Last 30 days
+    unsubscribed: number;
+  };
+  community?: {
+    provider: string;
+    members: number;
+    online: number;
   };
 }
 
@@ -484,6 +795,15 @@ export interface Settings {
   dashboardLayout?: GridLayoutItem[]; // User's custom grid layout
 }
 
+// ==========================================
+// Notifications
+// ==========================================
+
+// Integrations Pulse posts digests and alerts to
+export const NOTIFICATION_INTEGRATIONS: IntegrationType[] = ['slack', 'discord'];
+
+export type NotificationLevel = 'info' | 'warning' | 'critical';
+
 // ==========================================
 // UI State Types
 // ==========================================
@@ -532,7 +852,9 @@ export type WidgetType =
   // Analytics widgets
   | 'stat-analytics-dau'
   | 'stat-analytics-mau'
-  | 'stat-analytics-wau';
+  | 'stat-analytics-wau'
+  // Error tracking widgets
+  | 'frontend-health';
 
 export interface DashboardWidget {
   id: string;